@@ -0,0 +1,91 @@
+//! Compares the `D128`-backed `Amount` with the scaled-integer `ScaledAmount`.
+//!
+//! ```text
+//! cargo bench --bench amount_backends
+//! ```
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use fixed_precision_calculations::domain::value_type::amount::Amount;
+use fixed_precision_calculations::domain::value_type::monetary_amount::MonetaryAmount;
+use fixed_precision_calculations::domain::value_type::rounding::RoundingMode;
+use fixed_precision_calculations::domain::value_type::scaled_amount::ScaledAmount;
+use fixed_precision_calculations::utility::generate_data::monetary_value_generator::MonetaryValueGenerator;
+use std::hint::black_box;
+
+const LINE_ITEMS: usize = 10_000;
+
+fn prices() -> Vec<i64> {
+    MonetaryValueGenerator::<2>::builder()
+        .seed(38)
+        .build()
+        .unwrap()
+        .amounts()
+        .take(LINE_ITEMS)
+        .map(|amount| amount.to_minor_units().unwrap())
+        .collect()
+}
+
+/// Net plus 19% VAT per line item, rounded per line.
+fn price_line_items<A: MonetaryAmount>(prices: &[A]) -> A {
+    prices
+        .iter()
+        .enumerate()
+        .map(|(index, price)| {
+            let net = price.checked_mul_int(index as i64 % 5 + 1).unwrap();
+            net.checked_add(net.mul_ratio(19, 100, RoundingMode::HalfEven).unwrap())
+                .unwrap()
+        })
+        .sum()
+}
+
+fn bench_backend<A: MonetaryAmount>(c: &mut Criterion, name: &str)
+where
+    A::Err: std::fmt::Debug,
+{
+    let minor_units = prices();
+    let strings: Vec<String> = minor_units
+        .iter()
+        .map(|minor_units| A::from_minor_units(*minor_units).to_string())
+        .collect();
+    let amounts: Vec<A> = minor_units
+        .iter()
+        .copied()
+        .map(A::from_minor_units)
+        .collect();
+
+    c.bench_function(&format!("{name}/construct"), |b| {
+        b.iter(|| {
+            minor_units
+                .iter()
+                .map(|minor_units| A::from_minor_units(black_box(*minor_units)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    c.bench_function(&format!("{name}/parse"), |b| {
+        b.iter(|| {
+            strings
+                .iter()
+                .map(|value| black_box(value).parse::<A>().unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+
+    c.bench_function(&format!("{name}/sum"), |b| {
+        b.iter(|| black_box(&amounts).iter().copied().sum::<A>())
+    });
+
+    c.bench_with_input(
+        BenchmarkId::new(format!("{name}/price_line_items"), LINE_ITEMS),
+        &amounts,
+        |b, amounts| b.iter(|| price_line_items(black_box(amounts))),
+    );
+}
+
+fn backends(c: &mut Criterion) {
+    bench_backend::<Amount<2>>(c, "d128");
+    bench_backend::<ScaledAmount<2>>(c, "scaled_i128");
+}
+
+criterion_group!(benches, backends);
+criterion_main!(benches);
@@ -0,0 +1,490 @@
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+use crate::domain::value_type::rounding::RoundingMode;
+use fastnum::D128;
+use std::collections::BTreeMap;
+use std::fmt::Formatter;
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DriftError {
+    #[error("value {index}: {source}")]
+    Parse {
+        index: usize,
+        source: ParseAmountError,
+    },
+    #[error("failed to read values: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("line {line} has no column {column}")]
+    MissingColumn { line: usize, column: usize },
+}
+
+/// Number representation used to accumulate the values.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Backend {
+    F64,
+    F32,
+    /// [`D128`] addition of values converted from [`f64`], as `get_total_d128` in
+    /// [`precision_loss_1`](crate::precision_loss_1) does, so the conversion errors accumulate.
+    D128,
+    /// Integer minor units, e.g. cents, in an [`i64`].
+    ScaledI64,
+}
+
+/// How large the rounding errors introduced by individual additions are.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorDistribution {
+    /// Additions that introduced no error at all.
+    pub exact: usize,
+    /// Additions with an error, keyed by decimal order of magnitude (e.g. -13 for 1.2e-13).
+    pub by_magnitude: BTreeMap<i32, usize>,
+    pub max: D128,
+    /// Mean absolute error over all additions, exact ones included.
+    pub mean: D128,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendReport {
+    pub backend: Backend,
+    /// Final total, converted exactly to a decimal.
+    pub total: D128,
+    /// `|total - exact total|`
+    pub absolute_drift: D128,
+    /// Absolute drift relative to the exact total, zero when the exact total is zero.
+    pub relative_drift: D128,
+    /// Index of the first value after which the running total, rounded to `DECIMALS`, no longer
+    /// matches the exact running total.
+    pub first_divergence: Option<usize>,
+    pub errors: ErrorDistribution,
+    /// Index of the value at which the accumulator overflowed, after which it stopped accumulating.
+    pub overflowed_at: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriftReport<const DECIMALS: usize> {
+    pub count: usize,
+    pub exact_total: Amount<DECIMALS>,
+    pub backends: Vec<BackendReport>,
+}
+
+/// Tracks a single backend, given its running total after each value.
+struct Tracker {
+    backend: Backend,
+    total: D128,
+    first_divergence: Option<usize>,
+    exact_operations: usize,
+    by_magnitude: BTreeMap<i32, usize>,
+    max_error: D128,
+    error_sum: D128,
+    overflowed_at: Option<usize>,
+}
+
+impl Tracker {
+    fn new(backend: Backend) -> Self {
+        Self {
+            backend,
+            total: D128::ZERO,
+            first_divergence: None,
+            exact_operations: 0,
+            by_magnitude: BTreeMap::new(),
+            max_error: D128::ZERO,
+            error_sum: D128::ZERO,
+            overflowed_at: None,
+        }
+    }
+
+    /// Records an addition: `error` is the rounding error of this addition alone, `total` the running
+    /// total the backend computed.
+    fn record<const DECIMALS: usize>(
+        &mut self,
+        index: usize,
+        error: D128,
+        total: D128,
+        exact_running_total: Amount<DECIMALS>,
+    ) {
+        let error = error.abs();
+
+        if error.is_zero() {
+            self.exact_operations += 1;
+        } else {
+            let magnitude =
+                error.digits_count() as i32 - 1 - error.fractional_digits_count() as i32;
+            *self.by_magnitude.entry(magnitude).or_default() += 1;
+            self.max_error = self.max_error.max(error);
+            self.error_sum += error;
+        }
+
+        let rounded = Amount::<DECIMALS>::from(total).round(RoundingMode::HalfEven);
+        if self.first_divergence.is_none() && rounded != exact_running_total {
+            self.first_divergence = Some(index);
+        }
+
+        self.total = total;
+    }
+
+    fn report<const DECIMALS: usize>(
+        self,
+        count: usize,
+        exact_total: Amount<DECIMALS>,
+    ) -> BackendReport {
+        let exact_total = exact_total.raw();
+        let absolute_drift = (self.total - exact_total).abs();
+        let relative_drift = if exact_total.is_zero() {
+            D128::ZERO
+        } else {
+            absolute_drift / exact_total.abs()
+        };
+        let mean = if count == 0 {
+            D128::ZERO
+        } else {
+            self.error_sum / D128::from_usize(count)
+        };
+
+        BackendReport {
+            backend: self.backend,
+            total: self.total,
+            absolute_drift,
+            relative_drift,
+            first_divergence: self.first_divergence,
+            errors: ErrorDistribution {
+                exact: self.exact_operations,
+                by_magnitude: self.by_magnitude,
+                max: self.max_error,
+                mean,
+            },
+            overflowed_at: self.overflowed_at,
+        }
+    }
+}
+
+/// Accumulates `values` with every [`Backend`] and compares each against the exact total.
+///
+/// Values are decimal strings such as those from
+/// [`generate_fake_monetary_values`](crate::utility::generate_data::generate_monetary_values::generate_fake_monetary_values),
+/// and are processed one at a time, so any number of them can be streamed through.
+pub fn analyse<const DECIMALS: usize, S: AsRef<str>>(
+    values: impl IntoIterator<Item = S>,
+) -> Result<DriftReport<DECIMALS>, DriftError> {
+    try_analyse(values.into_iter().map(Ok))
+}
+
+/// Like [`analyse`], for values that may fail to be read, e.g. from [`read_csv_column`].
+pub fn try_analyse<const DECIMALS: usize, S: AsRef<str>>(
+    values: impl IntoIterator<Item = Result<S, DriftError>>,
+) -> Result<DriftReport<DECIMALS>, DriftError> {
+    let mut exact = Amount::<DECIMALS>::ZERO;
+    let mut f64_total = 0_f64;
+    let mut f32_total = 0_f32;
+    let mut d128_total = D128::ZERO;
+    let mut i64_total = 0_i64;
+
+    let mut f64_tracker = Tracker::new(Backend::F64);
+    let mut f32_tracker = Tracker::new(Backend::F32);
+    let mut d128_tracker = Tracker::new(Backend::D128);
+    let mut i64_tracker = Tracker::new(Backend::ScaledI64);
+
+    let mut count = 0;
+    for (index, value) in values.into_iter().enumerate() {
+        let value = value?;
+        let value = value.as_ref().trim();
+        let amount: Amount<DECIMALS> = value
+            .parse()
+            .map_err(|source| DriftError::Parse { index, source })?;
+        exact += amount;
+        count += 1;
+
+        let invalid = || DriftError::Parse {
+            index,
+            source: ParseAmountError::Invalid(value.to_string()),
+        };
+
+        let as_f64: f64 = value.parse().map_err(|_| invalid())?;
+        let (total, error) = two_sum(f64_total, as_f64);
+        f64_total = total;
+        f64_tracker.record(
+            index,
+            D128::from_f64(error),
+            D128::from_f64(f64_total),
+            exact,
+        );
+
+        let as_f32: f32 = value.parse().map_err(|_| invalid())?;
+        let (total, error) = two_sum(f32_total, as_f32);
+        f32_total = total;
+        f32_tracker.record(
+            index,
+            D128::from_f32(error),
+            D128::from_f32(f32_total),
+            exact,
+        );
+
+        // The addition is exact; the error is that of converting the f64 to a decimal.
+        let as_d128 = D128::from_f64(as_f64);
+        d128_total += as_d128;
+        d128_tracker.record(index, as_d128 - amount.raw(), d128_total, exact);
+
+        if i64_tracker.overflowed_at.is_none() {
+            match amount
+                .to_minor_units()
+                .and_then(|minor_units| i64_total.checked_add(minor_units))
+            {
+                Some(total) => {
+                    i64_total = total;
+                    let total = Amount::<DECIMALS>::new_scaled_i64(total).raw();
+                    i64_tracker.record(index, D128::ZERO, total, exact);
+                }
+                None => i64_tracker.overflowed_at = Some(index),
+            }
+        }
+    }
+
+    Ok(DriftReport {
+        count,
+        exact_total: exact,
+        backends: vec![
+            f64_tracker.report(count, exact),
+            f32_tracker.report(count, exact),
+            d128_tracker.report(count, exact),
+            i64_tracker.report(count, exact),
+        ],
+    })
+}
+
+/// Adds two floats, returning the rounded sum and the exact rounding error of that addition (Knuth's
+/// TwoSum, exact for [`f32`] and [`f64`] alike).
+fn two_sum<F>(a: F, b: F) -> (F, F)
+where
+    F: Copy + std::ops::Add<Output = F> + std::ops::Sub<Output = F>,
+{
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Streams the values of `column` (0-based) of a comma-separated file, one per line, skipping a
+/// header line if the first line does not hold a number there.
+pub fn read_csv_column(
+    path: impl AsRef<Path>,
+    column: usize,
+) -> Result<impl Iterator<Item = Result<String, DriftError>>, DriftError> {
+    let reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_path(path)?;
+
+    Ok(reader
+        .into_records()
+        .enumerate()
+        .filter_map(move |(index, record)| {
+            let record = match record {
+                Ok(record) => record,
+                Err(error) => return Some(Err(error.into())),
+            };
+            let Some(value) = record.get(column) else {
+                let line = record
+                    .position()
+                    .map_or(index + 1, |position| position.line() as usize);
+                return Some(Err(DriftError::MissingColumn { line, column }));
+            };
+
+            match index == 0 && value.parse::<D128>().is_err() {
+                true => None,
+                false => Some(Ok(value.to_string())),
+            }
+        }))
+}
+
+impl std::fmt::Display for Backend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Backend::F64 => "f64",
+            Backend::F32 => "f32",
+            Backend::D128 => "D128",
+            Backend::ScaledI64 => "scaled i64",
+        };
+
+        f.pad(name)
+    }
+}
+
+impl<const DECIMALS: usize> std::fmt::Display for DriftReport<DECIMALS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "values:      {}", self.count)?;
+        writeln!(f, "exact total: {}", self.exact_total)?;
+
+        for report in &self.backends {
+            writeln!(f)?;
+            writeln!(f, "[{}]", report.backend)?;
+            writeln!(f, "  total:            {}", report.total)?;
+            writeln!(
+                f,
+                "  absolute drift:   {}",
+                report.absolute_drift.to_scientific_notation()
+            )?;
+            writeln!(
+                f,
+                "  relative drift:   {}",
+                report.relative_drift.to_scientific_notation()
+            )?;
+
+            match report.first_divergence {
+                Some(index) => writeln!(
+                    f,
+                    "  first divergence: value #{index} (at {DECIMALS} decimals)"
+                )?,
+                None => writeln!(f, "  first divergence: none")?,
+            }
+            if let Some(index) = report.overflowed_at {
+                writeln!(f, "  overflowed at:    value #{index}")?;
+            }
+
+            writeln!(f, "  exact additions:  {}", report.errors.exact)?;
+            writeln!(
+                f,
+                "  max error:        {}",
+                report.errors.max.to_scientific_notation()
+            )?;
+            writeln!(
+                f,
+                "  mean error:       {}",
+                report.errors.mean.to_scientific_notation()
+            )?;
+            for (magnitude, count) in &report.errors.by_magnitude {
+                writeln!(f, "    ~1e{magnitude}: {count}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Backend, BackendReport, DriftError, DriftReport, analyse, read_csv_column, try_analyse,
+        two_sum,
+    };
+    use crate::domain::value_type::amount::Amount;
+    use crate::precision_loss_1::get_total_d128;
+    use crate::utility::generate_data::generate_monetary_values::generate_fake_monetary_values;
+    use fastnum::D128;
+
+    const DATA: [&str; 10] = [
+        "548.15", "83.15", "805.28", "142.66", "107.19", "852.18", "50.29", "781.65", "887.29",
+        "988.73",
+    ];
+
+    fn backend<const DECIMALS: usize>(
+        report: &DriftReport<DECIMALS>,
+        backend: Backend,
+    ) -> &BackendReport {
+        report
+            .backends
+            .iter()
+            .find(|r| r.backend == backend)
+            .unwrap()
+    }
+
+    #[test]
+    fn scaled_i64_does_not_drift() {
+        let report = analyse::<2, _>(DATA).unwrap();
+        let i64_report = backend(&report, Backend::ScaledI64);
+
+        assert_eq!(report.count, 10);
+        assert_eq!(report.exact_total, "5246.57".parse::<Amount<2>>().unwrap());
+        assert!(i64_report.absolute_drift.is_zero());
+        assert_eq!(i64_report.first_divergence, None);
+        assert_eq!(i64_report.errors.exact, 10);
+    }
+
+    #[test]
+    fn d128_from_f64_drifts() {
+        let report = analyse::<2, _>(DATA).unwrap();
+        let d128_report = backend(&report, Backend::D128);
+        let data: Vec<&str> = DATA.to_vec();
+
+        // Same total as `precision_loss_1::get_total_d128`.
+        assert_eq!(d128_report.total, get_total_d128(&data));
+        assert!(!d128_report.absolute_drift.is_zero());
+        assert!(d128_report.errors.max > D128::ZERO);
+    }
+
+    #[test]
+    fn f64_drifts() {
+        let report = analyse::<2, _>(DATA).unwrap();
+        let f64_report = &report.backends[0];
+
+        assert_eq!(f64_report.backend, Backend::F64);
+        // Same total as in `precision_loss_1::tests::demo_f64_precision_loss`.
+        assert!(
+            f64_report
+                .total
+                .to_string()
+                .starts_with("5246.569999999999")
+        );
+        assert!(!f64_report.absolute_drift.is_zero());
+        assert!(f64_report.relative_drift < D128::from_f64(1e-15));
+        assert!(f64_report.errors.max > D128::ZERO);
+    }
+
+    #[test]
+    fn f32_diverges_at_two_decimals() {
+        let values = generate_fake_monetary_values(10_000);
+        let report = analyse::<2, _>(&values).unwrap();
+        let f32_report = backend(&report, Backend::F32);
+
+        assert!(f32_report.first_divergence.is_some());
+        assert!(!f32_report.errors.by_magnitude.is_empty());
+    }
+
+    #[test]
+    fn two_sum_error() {
+        assert_eq!(
+            two_sum(0.1, 0.2),
+            (0.30000000000000004, -2.7755575615628914e-17)
+        );
+        assert_eq!(two_sum(1.5, 2.25), (3.75, 0.0));
+        // Exponents 40 bits apart: the f32 sum loses all of `b`, which an f64 sum would keep.
+        assert_eq!(two_sum(1.0e12_f32, 1.0e0_f32), (1.0e12_f32, 1.0_f32));
+    }
+
+    #[test]
+    fn scaled_i64_overflow() {
+        let report = analyse::<2, _>(["92233720368547758.07", "0.01"]).unwrap();
+        assert_eq!(backend(&report, Backend::ScaledI64).overflowed_at, Some(1));
+
+        let huge = "9".repeat(36);
+        let report = analyse::<2, _>([huge.as_str()]).unwrap();
+        assert_eq!(backend(&report, Backend::ScaledI64).overflowed_at, Some(0));
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        let err = analyse::<2, _>(["1.00", "abc"]).unwrap_err();
+
+        assert!(matches!(err, DriftError::Parse { index: 1, .. }), "{err}");
+    }
+
+    #[test]
+    fn csv_column() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("drift.csv");
+        std::fs::write(&path, "id,amount\n1,548.15\n2,\"1,083.15\"\n\n3,805.28\n").unwrap();
+
+        assert_eq!(
+            read_csv_column(&path, 1)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            ["548.15", "1,083.15", "805.28"]
+        );
+        assert!(matches!(
+            read_csv_column(&path, 2).unwrap().next(),
+            Some(Err(DriftError::MissingColumn { line: 1, column: 2 }))
+        ));
+        let report = try_analyse::<2, _>(read_csv_column(&path, 0).unwrap()).unwrap();
+        assert_eq!(report.exact_total, Amount::new_scaled_i32(600));
+    }
+}
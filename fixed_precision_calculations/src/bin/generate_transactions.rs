@@ -0,0 +1,45 @@
+//! Writes synthetic transactions to stdout, for load-testing ETL and reconciliation code.
+//!
+//! ```text
+//! cargo run --release --bin generate_transactions -- csv 365 1000 42 > transactions.csv
+//! cargo run --release --bin generate_transactions -- jsonl 30 > transactions.jsonl
+//! ```
+
+use fixed_precision_calculations::utility::generate_data::transaction_generator::{
+    ExportFormat, Seasonality, TransactionGenerator, write_transactions,
+};
+use std::io::{BufWriter, Write};
+
+const USAGE: &str = "usage: generate_transactions csv|jsonl [DAYS] [PER_DAY] [SEED]";
+
+fn usage<E>(_: E) -> anyhow::Error {
+    anyhow::anyhow!(USAGE)
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let (format, rest) = match args.split_first() {
+        Some((format, rest)) if format == "csv" => (ExportFormat::Csv, rest),
+        Some((format, rest)) if format == "jsonl" => (ExportFormat::JsonLines, rest),
+        _ => anyhow::bail!(USAGE),
+    };
+
+    let mut builder = TransactionGenerator::builder().seasonality(Seasonality::RETAIL);
+    if let Some(days) = rest.first() {
+        builder = builder.days(days.parse().map_err(usage)?);
+    }
+    if let Some(per_day) = rest.get(1) {
+        builder = builder.transactions_per_day(per_day.parse().map_err(usage)?);
+    }
+    if let Some(seed) = rest.get(2) {
+        builder = builder.seed(seed.parse().map_err(usage)?);
+    }
+
+    let mut generator = builder.build()?;
+    let mut stdout = BufWriter::new(std::io::stdout().lock());
+    write_transactions(&mut stdout, format, generator.transactions())?;
+    stdout.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Quantifies how far `f64`, `f32`, `D128` and scaled-`i64` totals drift from the exact total.
+//!
+//! ```text
+//! cargo run --release --bin precision_drift -- 1000000
+//! cargo run --release --bin precision_drift -- --csv export.csv --column 3
+//! ```
+
+use fixed_precision_calculations::analysis::precision_drift::{
+    analyse, read_csv_column, try_analyse,
+};
+use fixed_precision_calculations::utility::generate_data::generate_monetary_values::generate_fake_monetary_values;
+
+const USAGE: &str = "usage: precision_drift [COUNT] | --csv PATH [--column INDEX]";
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let report = match args.as_slice() {
+        [] => analyse::<2, _>(generate_fake_monetary_values(100_000))?,
+        [count] => analyse(generate_fake_monetary_values(
+            count.parse().map_err(|_| anyhow::anyhow!(USAGE))?,
+        ))?,
+        [flag, path] if flag == "--csv" => try_analyse(read_csv_column(path, 0)?)?,
+        [flag, path, column_flag, column] if flag == "--csv" && column_flag == "--column" => {
+            try_analyse(read_csv_column(
+                path,
+                column.parse().map_err(|_| anyhow::anyhow!(USAGE))?,
+            )?)?
+        }
+        _ => anyhow::bail!(USAGE),
+    };
+
+    print!("{report}");
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::rounding::RoundingMode;
+use fastnum::D128;
+use rayon::iter::ParallelIterator;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Count, total and extremes of a collection of amounts, see [`ParAmountIterator::par_stats`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AmountStats<const DECIMALS: usize> {
+    pub count: usize,
+    pub sum: Amount<DECIMALS>,
+    pub min: Option<Amount<DECIMALS>>,
+    pub max: Option<Amount<DECIMALS>>,
+}
+
+impl<const DECIMALS: usize> AmountStats<DECIMALS> {
+    const EMPTY: Self = Self {
+        count: 0,
+        sum: Amount::ZERO,
+        min: None,
+        max: None,
+    };
+
+    fn push(self, amount: Amount<DECIMALS>) -> Self {
+        self.merge(Self {
+            count: 1,
+            sum: amount,
+            min: Some(amount),
+            max: Some(amount),
+        })
+    }
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+            min: self.min.into_iter().chain(other.min).min(),
+            max: self.max.into_iter().chain(other.max).max(),
+        }
+    }
+
+    /// Mean rounded to `DECIMALS` decimals with `mode`, `None` when there are no amounts.
+    pub fn mean(&self, mode: RoundingMode) -> Option<Amount<DECIMALS>> {
+        (self.count > 0)
+            .then(|| Amount::from(self.sum.raw() / D128::from_usize(self.count)).round(mode))
+    }
+}
+
+/// Parallel aggregations over amounts, e.g. `amounts.par_iter().par_sum()` for a slice, or
+/// `amounts.into_iter().par_bridge().par_sum()` for any other iterator.
+///
+/// Decimal addition of amounts is exact, and therefore associative and commutative, so the results
+/// are identical to the sequential ones whatever the number of threads and however rayon splits the
+/// work. This only stops holding once a sum needs more digits than a [`D128`] holds.
+///
+/// # Panics
+///
+/// Like [`std::iter::Sum`], panics if a sum overflows.
+pub trait ParAmountIterator<const DECIMALS: usize, A>: ParallelIterator<Item = A>
+where
+    A: Borrow<Amount<DECIMALS>> + Send,
+{
+    fn par_sum(self) -> Amount<DECIMALS> {
+        self.map(|amount| *amount.borrow())
+            .reduce(|| Amount::ZERO, |left, right| left + right)
+    }
+
+    /// Sums amounts by `key`, e.g. by sign or by order of magnitude.
+    fn par_group_sum_by<K, F>(self, key: F) -> HashMap<K, Amount<DECIMALS>>
+    where
+        K: Eq + Hash + Send,
+        F: Fn(&Amount<DECIMALS>) -> K + Sync + Send,
+    {
+        self.fold(HashMap::new, |mut groups, amount| {
+            let amount = *amount.borrow();
+            *groups.entry(key(&amount)).or_insert(Amount::ZERO) += amount;
+            groups
+        })
+        .reduce(HashMap::new, |mut left, right| {
+            for (key, amount) in right {
+                *left.entry(key).or_insert(Amount::ZERO) += amount;
+            }
+            left
+        })
+    }
+
+    fn par_stats(self) -> AmountStats<DECIMALS> {
+        self.fold(
+            || AmountStats::EMPTY,
+            |stats, amount| stats.push(*amount.borrow()),
+        )
+        .reduce(|| AmountStats::EMPTY, AmountStats::merge)
+    }
+}
+
+impl<I, A, const DECIMALS: usize> ParAmountIterator<DECIMALS, A> for I
+where
+    I: ParallelIterator<Item = A>,
+    A: Borrow<Amount<DECIMALS>> + Send,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AmountStats, ParAmountIterator};
+    use crate::domain::aggregate::AmountIterator;
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use crate::precision_loss_1::get_total_d128;
+    use crate::utility::generate_data::monetary_value_generator::MonetaryValueGenerator;
+    use fastnum::D128;
+    use rayon::prelude::*;
+    use std::collections::HashMap;
+
+    fn amounts(values: &[&str]) -> Vec<Amount<2>> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    fn with_threads<T: Send>(threads: usize, f: impl FnOnce() -> T + Send) -> T {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(f)
+    }
+
+    #[test]
+    fn par_sum_matches_sequential_on_millions_of_values() {
+        let values: Vec<String> = MonetaryValueGenerator::<2>::builder()
+            .seed(37)
+            .negative_ratio(0.3)
+            .build()
+            .unwrap()
+            .strings()
+            .take(2_000_000)
+            .collect();
+        let amounts: Vec<Amount<2>> = values.iter().map(|value| value.parse().unwrap()).collect();
+        let strs: Vec<&str> = values.iter().map(String::as_str).collect();
+        // `get_total_d128` goes through `f64`, which is slow, so chunks of it are totalled on all
+        // cores; its representation errors stay far below a cent either way.
+        let sequential = strs
+            .par_chunks(100_000)
+            .map(get_total_d128)
+            .sum::<D128>()
+            .round(2);
+
+        assert_eq!(
+            amounts.iter().copied().checked_sum().unwrap().raw(),
+            sequential
+        );
+        for threads in [1, 2, 3, 8] {
+            let parallel = with_threads(threads, || amounts.par_iter().par_sum());
+
+            assert_eq!(parallel.raw(), sequential, "{threads} threads");
+            assert_eq!(parallel.to_string(), sequential.to_string());
+        }
+    }
+
+    #[test]
+    fn par_group_sum_by() {
+        let values = amounts(&["10.00", "-2.50", "0.01", "-0.49", "100.00"]);
+
+        let groups: HashMap<bool, Amount<2>> = with_threads(4, || {
+            values.par_iter().par_group_sum_by(Amount::is_negative)
+        });
+
+        assert_eq!(groups[&false], "110.01".parse().unwrap());
+        assert_eq!(groups[&true], "-2.99".parse().unwrap());
+    }
+
+    #[test]
+    fn par_stats() {
+        let values = amounts(&["548.15", "-83.15", "805.28", "142.66"]);
+        let stats = with_threads(3, || values.iter().par_bridge().par_stats());
+
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.sum, "1412.94".parse().unwrap());
+        assert_eq!(stats.min, Some("-83.15".parse().unwrap()));
+        assert_eq!(stats.max, Some("805.28".parse().unwrap()));
+        assert_eq!(
+            stats.mean(RoundingMode::HalfEven),
+            Some("353.24".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn par_stats_empty() {
+        let stats = Vec::<Amount<2>>::new().into_par_iter().par_stats();
+
+        assert_eq!(stats, AmountStats::EMPTY);
+        assert_eq!(stats.mean(RoundingMode::HalfEven), None);
+    }
+}
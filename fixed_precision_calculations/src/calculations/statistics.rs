@@ -0,0 +1,318 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::percentage::Percentage;
+use crate::domain::value_type::rounding::RoundingMode;
+use fastnum::D128;
+use std::borrow::Borrow;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum StatisticsError {
+    #[error("no values to compute a statistic over")]
+    Empty,
+    #[error("sample statistics need at least 2 values, got {0}")]
+    InsufficientData(usize),
+    #[error("quantile {0} is outside 0..=1")]
+    QuantileOutOfRange(String),
+    #[error("weights must not be negative and must not sum to zero")]
+    InvalidWeights,
+}
+
+/// Whether the values are the whole population, or a sample of it (Bessel's correction, n - 1).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Variance {
+    Population,
+    Sample,
+}
+
+/// Exact decimal statistics over monetary values, without converting to [`f64`].
+///
+/// All outputs are rounded once, at the end, to the declared `SCALE` using the configured rounding
+/// mode, e.g. `Statistics::<4>::new(RoundingMode::HalfEven).mean(&cents)` gives an `Amount<4>`.
+///
+/// Inputs can be anything iterable over [`Amount`] or `&Amount`, i.e. slices as well as iterators.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Statistics<const SCALE: usize> {
+    mode: RoundingMode,
+}
+
+impl<const SCALE: usize> Statistics<SCALE> {
+    pub const fn new(mode: RoundingMode) -> Self {
+        Self { mode }
+    }
+
+    pub fn mean<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = A>,
+    ) -> Result<Amount<SCALE>, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        let (total, count) = values
+            .into_iter()
+            .fold((D128::ZERO, 0_usize), |(total, count), value| {
+                (total + value.borrow().raw(), count + 1)
+            });
+
+        if count == 0 {
+            return Err(StatisticsError::Empty);
+        }
+
+        Ok(self.output(total / D128::from_usize(count)))
+    }
+
+    /// Mean of values weighted by `weight`, e.g. prices weighted by quantity.
+    pub fn weighted_mean<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = (A, D128)>,
+    ) -> Result<Amount<SCALE>, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        let mut total = D128::ZERO;
+        let mut weights = D128::ZERO;
+        let mut count = 0_usize;
+
+        for (value, weight) in values {
+            if weight.is_negative() {
+                return Err(StatisticsError::InvalidWeights);
+            }
+
+            total += value.borrow().raw() * weight;
+            weights += weight;
+            count += 1;
+        }
+
+        match (count, weights.is_zero()) {
+            (0, _) => Err(StatisticsError::Empty),
+            (_, true) => Err(StatisticsError::InvalidWeights),
+            _ => Ok(self.output(total / weights)),
+        }
+    }
+
+    pub fn median<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = A>,
+    ) -> Result<Amount<SCALE>, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        self.quantile(values, D128::HALF)
+    }
+
+    /// The `q`-quantile (0 ≤ q ≤ 1), interpolating linearly between the two closest ranks.
+    ///
+    /// This is the same definition as Excel's `PERCENTILE.INC` and numpy's default, so results can be
+    /// compared with existing reports.
+    pub fn quantile<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = A>,
+        q: D128,
+    ) -> Result<Amount<SCALE>, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        if q.is_negative() || q > D128::ONE {
+            return Err(StatisticsError::QuantileOutOfRange(q.to_string()));
+        }
+
+        let mut sorted: Vec<D128> = values.into_iter().map(|v| v.borrow().raw()).collect();
+        if sorted.is_empty() {
+            return Err(StatisticsError::Empty);
+        }
+        sorted.sort();
+
+        let rank = q * D128::from_usize(sorted.len() - 1);
+        let lower = rank.floor();
+        let index = lower
+            .to_usize()
+            .expect("rank is within the number of values");
+
+        let value = match sorted.get(index + 1) {
+            Some(upper) => sorted[index] + (rank - lower) * (*upper - sorted[index]),
+            None => sorted[index],
+        };
+
+        Ok(self.output(value))
+    }
+
+    /// Percentile, e.g. 95% for the 95th percentile; see [`Self::quantile`].
+    pub fn percentile<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = A>,
+        percentile: Percentage,
+    ) -> Result<Amount<SCALE>, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        self.quantile(values, percentile.ratio())
+    }
+
+    /// Variance, in squared units of the values.
+    ///
+    /// Computed as `(n·Σx² - (Σx)²) / (n·(n - k))`, so that the only inexact step is the final
+    /// division.
+    pub fn variance<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = A>,
+        kind: Variance,
+    ) -> Result<Amount<SCALE>, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        self.exact_variance(values, kind).map(|v| self.output(v))
+    }
+
+    pub fn std_dev<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = A>,
+        kind: Variance,
+    ) -> Result<Amount<SCALE>, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        self.exact_variance(values, kind)
+            .map(|v| self.output(v.sqrt()))
+    }
+
+    fn exact_variance<const DECIMALS: usize, A>(
+        &self,
+        values: impl IntoIterator<Item = A>,
+        kind: Variance,
+    ) -> Result<D128, StatisticsError>
+    where
+        A: Borrow<Amount<DECIMALS>>,
+    {
+        let (sum, sum_of_squares, count) = values.into_iter().fold(
+            (D128::ZERO, D128::ZERO, 0_usize),
+            |(sum, sum_of_squares, count), value| {
+                let value = value.borrow().raw();
+                (sum + value, sum_of_squares + value * value, count + 1)
+            },
+        );
+
+        let degrees_of_freedom = match (kind, count) {
+            (_, 0) => return Err(StatisticsError::Empty),
+            (Variance::Sample, 1) => return Err(StatisticsError::InsufficientData(count)),
+            (Variance::Population, _) => count,
+            (Variance::Sample, _) => count - 1,
+        };
+
+        let n = D128::from_usize(count);
+        Ok((n * sum_of_squares - sum * sum) / (n * D128::from_usize(degrees_of_freedom)))
+    }
+
+    fn output(&self, value: D128) -> Amount<SCALE> {
+        Amount::from(value).round(self.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Statistics, StatisticsError, Variance};
+    use crate::domain::value_type::amount::{Amount, Cents};
+    use crate::domain::value_type::percentage::Percentage;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use fastnum::{D128, dec128};
+    use rstest::rstest;
+
+    fn cents(values: &[&str]) -> Vec<Cents> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    const STATS: Statistics<4> = Statistics::new(RoundingMode::HalfEven);
+
+    #[test]
+    fn mean_over_slice_and_iterator() {
+        let values = cents(&["548.15", "83.15", "805.28", "142.66"]);
+
+        let from_slice: Amount<4> = STATS.mean(&values).unwrap();
+        let from_iter: Amount<4> = STATS
+            .mean(values.into_iter().filter(|v| !v.is_zero()))
+            .unwrap();
+
+        assert_eq!(from_slice.to_string(), "394.8100");
+        assert_eq!(from_slice, from_iter);
+    }
+
+    #[test]
+    fn declared_scale() {
+        let values = cents(&["1.00", "2.00", "2.00"]);
+
+        assert_eq!(STATS.mean(&values).unwrap().to_string(), "1.6667");
+        assert_eq!(
+            Statistics::<0>::new(RoundingMode::Down)
+                .mean(&values)
+                .unwrap()
+                .to_string(),
+            "1"
+        );
+    }
+
+    #[rstest]
+    #[case(&["3.00", "1.00", "2.00"], "2.0000")]
+    #[case(&["4.00", "1.00", "3.00", "2.00"], "2.5000")]
+    #[case(&["0.01", "0.02"], "0.0150")]
+    fn median(#[case] values: &[&str], #[case] expected: &str) {
+        assert_eq!(STATS.median(cents(values)).unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(dec128!(0), "15.0000")]
+    #[case(dec128!(0.25), "20.0000")]
+    #[case(dec128!(0.4), "29.0000")]
+    #[case(dec128!(0.9), "46.0000")]
+    #[case(dec128!(1), "50.0000")]
+    fn quantile(#[case] q: D128, #[case] expected: &str) {
+        let values = cents(&["50.00", "15.00", "40.00", "20.00", "35.00"]);
+
+        assert_eq!(STATS.quantile(&values, q).unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn percentile() {
+        let values = cents(&["50.00", "15.00", "40.00", "20.00", "35.00"]);
+        let p90 = STATS.percentile(&values, Percentage::new(dec128!(90)));
+
+        assert_eq!(p90, Ok("46.00".parse().unwrap()));
+    }
+
+    #[test]
+    fn weighted_mean() {
+        let prices = cents(&["10.00", "20.00"]);
+        let weighted = prices.iter().zip([dec128!(3), dec128!(1)]);
+
+        assert_eq!(STATS.weighted_mean(weighted), Ok("12.50".parse().unwrap()));
+    }
+
+    #[rstest]
+    #[case(Variance::Population, "4.0000", "2.0000")]
+    #[case(Variance::Sample, "4.5714", "2.1381")]
+    fn variance_and_std_dev(#[case] kind: Variance, #[case] variance: &str, #[case] std_dev: &str) {
+        let values = cents(&[
+            "2.00", "4.00", "4.00", "4.00", "5.00", "5.00", "7.00", "9.00",
+        ]);
+
+        assert_eq!(STATS.variance(&values, kind).unwrap().to_string(), variance);
+        assert_eq!(STATS.std_dev(&values, kind).unwrap().to_string(), std_dev);
+    }
+
+    #[test]
+    fn errors() {
+        let empty: Vec<Cents> = Vec::new();
+        let one = cents(&["1.00"]);
+
+        assert_eq!(STATS.mean(&empty), Err(StatisticsError::Empty));
+        assert_eq!(STATS.median(&empty), Err(StatisticsError::Empty));
+        assert_eq!(
+            STATS.variance(&one, Variance::Sample),
+            Err(StatisticsError::InsufficientData(1))
+        );
+        assert_eq!(
+            STATS.quantile(&one, dec128!(1.5)),
+            Err(StatisticsError::QuantileOutOfRange("1.5".to_string()))
+        );
+        assert_eq!(
+            STATS.weighted_mean(one.iter().map(|v| (v, D128::ZERO))),
+            Err(StatisticsError::InvalidWeights)
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::percentage::Percentage;
+use crate::domain::value_type::rounding::RoundingMode;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TaxTableError {
+    #[error("failed to read tax table: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid TOML tax table: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("invalid JSON tax table: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("unsupported tax table format '{0}', expected a .toml or .json file")]
+    UnsupportedFormat(String),
+    #[error("tax table '{0}' has no brackets")]
+    Empty(String),
+    #[error("bracket thresholds must be strictly ascending, found {next} after {previous}")]
+    NotAscending { previous: String, next: String },
+    #[error("negative value in bracket starting at {0}")]
+    Negative(String),
+}
+
+/// A single band of a progressive tax table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxBracket<const DECIMALS: usize> {
+    /// Income from which this bracket applies; it ends where the next bracket starts.
+    pub threshold: Amount<DECIMALS>,
+    /// Marginal rate applied to the part of the income that falls within this bracket.
+    pub rate: Percentage,
+    /// Flat amount levied once income exceeds `threshold`, on top of the marginal tax.
+    #[serde(default)]
+    pub fixed_amount: Option<Amount<DECIMALS>>,
+}
+
+/// At which point the computed tax is rounded to `DECIMALS` decimals.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingStage {
+    /// Round the tax of each bracket, then sum the rounded values.
+    PerBracket,
+    /// Sum the exact tax of all brackets and round once.
+    #[default]
+    Total,
+}
+
+/// Jurisdiction-specific rounding rules.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TaxRounding {
+    #[serde(default)]
+    pub mode: RoundingMode,
+    #[serde(default)]
+    pub stage: RoundingStage,
+    /// Decimals kept for the effective rate, in percentage points (e.g. 2 → 19.05%).
+    #[serde(default = "TaxRounding::default_rate_decimals")]
+    pub rate_decimals: i16,
+}
+
+impl TaxRounding {
+    const fn default_rate_decimals() -> i16 {
+        2
+    }
+}
+
+impl Default for TaxRounding {
+    fn default() -> Self {
+        Self {
+            mode: RoundingMode::default(),
+            stage: RoundingStage::default(),
+            rate_decimals: Self::default_rate_decimals(),
+        }
+    }
+}
+
+/// Progressive tax table, i.e. thresholds with marginal rates, as loaded from a TOML or JSON file.
+///
+/// ```toml
+/// jurisdiction = "UK 2024/25"
+///
+/// [rounding]
+/// mode = "down"
+/// stage = "total"
+///
+/// [[brackets]]
+/// threshold = "0"
+/// rate = "0%"
+///
+/// [[brackets]]
+/// threshold = "12570.00"
+/// rate = "20%"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxTable<const DECIMALS: usize> {
+    pub jurisdiction: String,
+    #[serde(default)]
+    pub rounding: TaxRounding,
+    pub brackets: Vec<TaxBracket<DECIMALS>>,
+}
+
+/// Tax due within a single bracket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketTax<const DECIMALS: usize> {
+    pub threshold: Amount<DECIMALS>,
+    /// Start of the next bracket, `None` for the top bracket.
+    pub upper: Option<Amount<DECIMALS>>,
+    pub rate: Percentage,
+    /// Part of the income taxed within this bracket.
+    pub taxable: Amount<DECIMALS>,
+    /// Marginal tax plus any fixed amount; rounded only with [`RoundingStage::PerBracket`].
+    pub tax: Amount<DECIMALS>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxAssessment<const DECIMALS: usize> {
+    pub income: Amount<DECIMALS>,
+    /// Brackets reached by the income, lowest first.
+    pub breakdown: Vec<BracketTax<DECIMALS>>,
+    pub total: Amount<DECIMALS>,
+    /// Total tax as a percentage of income.
+    pub effective_rate: Percentage,
+    /// Rate applied to the next unit of income.
+    pub marginal_rate: Percentage,
+}
+
+impl<const DECIMALS: usize> TaxTable<DECIMALS> {
+    pub fn new(
+        jurisdiction: impl Into<String>,
+        rounding: TaxRounding,
+        brackets: Vec<TaxBracket<DECIMALS>>,
+    ) -> Result<Self, TaxTableError> {
+        Self {
+            jurisdiction: jurisdiction.into(),
+            rounding,
+            brackets,
+        }
+        .validated()
+    }
+
+    pub fn from_toml_str(s: &str) -> Result<Self, TaxTableError> {
+        toml::from_str::<Self>(s)?.validated()
+    }
+
+    pub fn from_json_str(s: &str) -> Result<Self, TaxTableError> {
+        serde_json::from_str::<Self>(s)?.validated()
+    }
+
+    /// Loads a table from disk, picking the format from the file extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, TaxTableError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&contents),
+            Some("json") => Self::from_json_str(&contents),
+            _ => Err(TaxTableError::UnsupportedFormat(path.display().to_string())),
+        }
+    }
+
+    fn validated(self) -> Result<Self, TaxTableError> {
+        if self.brackets.is_empty() {
+            return Err(TaxTableError::Empty(self.jurisdiction));
+        }
+
+        for bracket in &self.brackets {
+            let negative_fixed = bracket
+                .fixed_amount
+                .is_some_and(|fixed| fixed.is_negative());
+
+            if bracket.threshold.is_negative()
+                || bracket.rate.points().is_negative()
+                || negative_fixed
+            {
+                return Err(TaxTableError::Negative(bracket.threshold.to_string()));
+            }
+        }
+
+        for pair in self.brackets.windows(2) {
+            if pair[1].threshold <= pair[0].threshold {
+                return Err(TaxTableError::NotAscending {
+                    previous: pair[0].threshold.to_string(),
+                    next: pair[1].threshold.to_string(),
+                });
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Computes the tax due on `income`; negative income is taxed as zero.
+    pub fn assess(&self, income: Amount<DECIMALS>) -> TaxAssessment<DECIMALS> {
+        let mode = self.rounding.mode;
+        let mut breakdown = Vec::new();
+
+        for (index, bracket) in self.brackets.iter().enumerate() {
+            if income <= bracket.threshold {
+                break;
+            }
+
+            let upper = self.brackets.get(index + 1).map(|next| next.threshold);
+            let taxable = match upper {
+                Some(upper) if income > upper => upper - bracket.threshold,
+                _ => income - bracket.threshold,
+            };
+
+            let mut tax = bracket.rate.of(taxable) + bracket.fixed_amount.unwrap_or(Amount::ZERO);
+            if self.rounding.stage == RoundingStage::PerBracket {
+                tax = tax.round(mode);
+            }
+
+            breakdown.push(BracketTax {
+                threshold: bracket.threshold,
+                upper,
+                rate: bracket.rate,
+                taxable,
+                tax,
+            });
+        }
+
+        let total = breakdown
+            .iter()
+            .fold(Amount::ZERO, |total, bracket| total + bracket.tax)
+            .round(mode);
+
+        let effective_rate = if income.is_zero() || breakdown.is_empty() {
+            Percentage::ZERO
+        } else {
+            Percentage::from_ratio(total.raw() / income.raw()).round(self.rounding.rate_decimals)
+        };
+
+        let marginal_rate = self
+            .brackets
+            .iter()
+            .rev()
+            .find(|bracket| bracket.threshold <= income)
+            .map(|bracket| bracket.rate)
+            .unwrap_or(Percentage::ZERO);
+
+        TaxAssessment {
+            income,
+            breakdown,
+            total,
+            effective_rate,
+            marginal_rate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RoundingStage, TaxRounding, TaxTable, TaxTableError};
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::percentage::Percentage;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use fastnum::dec128;
+    use rstest::rstest;
+
+    const UK_TOML: &str = r#"
+        jurisdiction = "UK 2024/25"
+
+        [rounding]
+        mode = "down"
+
+        [[brackets]]
+        threshold = "0"
+        rate = "0%"
+
+        [[brackets]]
+        threshold = "12570.00"
+        rate = "20%"
+
+        [[brackets]]
+        threshold = "50270.00"
+        rate = "40%"
+
+        [[brackets]]
+        threshold = "125140.00"
+        rate = "45%"
+    "#;
+
+    #[rstest]
+    #[case("0", "0.00", "0%", "0%")]
+    #[case("12570.00", "0.00", "0%", "20%")]
+    #[case("30000.00", "3486.00", "11.62%", "20%")]
+    #[case("60000.00", "11432.00", "19.05%", "40%")]
+    #[case("150000.00", "48675.00", "32.45%", "45%")]
+    #[case("12570.03", "0.00", "0%", "20%")]
+    fn assess_uk(
+        #[case] income: &str,
+        #[case] total: &str,
+        #[case] effective: &str,
+        #[case] marginal: &str,
+    ) {
+        let table = TaxTable::<2>::from_toml_str(UK_TOML).unwrap();
+        let assessment = table.assess(income.parse().unwrap());
+
+        assert_eq!(assessment.total.to_string(), total);
+        assert_eq!(
+            assessment.effective_rate,
+            effective.parse::<Percentage>().unwrap()
+        );
+        assert_eq!(
+            assessment.marginal_rate,
+            marginal.parse::<Percentage>().unwrap()
+        );
+    }
+
+    #[test]
+    fn breakdown_per_bracket() {
+        let table = TaxTable::<2>::from_toml_str(UK_TOML).unwrap();
+        let assessment = table.assess("60000.00".parse().unwrap());
+
+        let taxable: Vec<String> = assessment
+            .breakdown
+            .iter()
+            .map(|b| b.taxable.to_string())
+            .collect();
+        assert_eq!(taxable, ["12570.00", "37700.00", "9730.00"]);
+
+        let tax: Vec<Amount<2>> = assessment.breakdown.iter().map(|b| b.tax).collect();
+        assert_eq!(
+            tax,
+            [
+                Amount::ZERO,
+                Amount::new_scaled_i32(754000),
+                Amount::new_scaled_i32(389200)
+            ]
+        );
+
+        assert_eq!(
+            assessment.breakdown[2].upper,
+            Some("125140.00".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn fixed_amount_from_json() {
+        let table = TaxTable::<2>::from_json_str(
+            r#"{
+                "jurisdiction": "Example",
+                "brackets": [
+                    { "threshold": "0", "rate": "10" },
+                    { "threshold": "1000.00", "rate": "25", "fixed_amount": "50.00" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let assessment = table.assess("1200.00".parse().unwrap());
+
+        // 1000.00 at 10%, then 200.00 at 25% plus a fixed 50.00
+        assert_eq!(assessment.total, Amount::new_scaled_i32(20000));
+        assert_eq!(assessment.marginal_rate, Percentage::new(dec128!(25)));
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfUp, RoundingStage::PerBracket, "0.02")]
+    #[case(RoundingMode::HalfUp, RoundingStage::Total, "0.03")]
+    #[case(RoundingMode::HalfEven, RoundingStage::Total, "0.02")]
+    fn rounding_per_jurisdiction(
+        #[case] mode: RoundingMode,
+        #[case] stage: RoundingStage,
+        #[case] expected: &str,
+    ) {
+        let rounding = TaxRounding {
+            mode,
+            stage,
+            ..TaxRounding::default()
+        };
+        let table = TaxTable::<2>::new(
+            "Rounding",
+            rounding,
+            vec![
+                super::TaxBracket {
+                    threshold: Amount::ZERO,
+                    rate: "12.5%".parse().unwrap(),
+                    fixed_amount: None,
+                },
+                super::TaxBracket {
+                    threshold: Amount::new_scaled_i32(10),
+                    rate: "12.5%".parse().unwrap(),
+                    fixed_amount: None,
+                },
+            ],
+        )
+        .unwrap();
+
+        // 0.10 and 0.10 at 12.5%, i.e. 0.0125 twice
+        let assessment = table.assess(Amount::new_scaled_i32(20));
+
+        assert_eq!(assessment.total.to_string(), expected);
+    }
+
+    #[test]
+    fn rejects_unordered_thresholds() {
+        let err = TaxTable::<2>::from_json_str(
+            r#"{ "jurisdiction": "Broken", "brackets": [
+                { "threshold": "100", "rate": "10" },
+                { "threshold": "50", "rate": "20" }
+            ] }"#,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, TaxTableError::NotAscending { .. }), "{err}");
+    }
+
+    #[test]
+    fn rejects_empty_table() {
+        let err =
+            TaxTable::<2>::from_toml_str("jurisdiction = \"Nowhere\"\nbrackets = []").unwrap_err();
+
+        assert!(matches!(err, TaxTableError::Empty(_)), "{err}");
+    }
+
+    #[test]
+    fn load_from_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("uk_tax.toml");
+        std::fs::write(&path, UK_TOML).unwrap();
+
+        let table = TaxTable::<2>::from_path(&path).unwrap();
+        assert_eq!(table.jurisdiction, "UK 2024/25");
+        assert_eq!(table.brackets.len(), 4);
+
+        let yaml = path.with_extension("yaml");
+        std::fs::write(&yaml, UK_TOML).unwrap();
+        let err = TaxTable::<2>::from_path(&yaml).unwrap_err();
+        assert!(
+            matches!(&err, TaxTableError::UnsupportedFormat(path) if path.ends_with("uk_tax.yaml")),
+            "{err}"
+        );
+
+        let err = TaxTable::<2>::from_path(dir.path().join("missing.toml")).unwrap_err();
+        assert!(matches!(err, TaxTableError::Io(_)), "{err}");
+    }
+}
@@ -0,0 +1,178 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::rounding::RoundingMode;
+use fastnum::D128;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum AggregateError {
+    #[error("sum overflowed at item {index}")]
+    Overflow { index: usize },
+    #[error("item {index} ({value}) has {found} decimals, expected at most {expected}")]
+    Scale {
+        index: usize,
+        value: String,
+        found: i16,
+        expected: usize,
+    },
+}
+
+/// Aggregations over iterators of [`Amount`], e.g. `amounts.iter().copied().mean(RoundingMode::HalfEven)`.
+///
+/// `min` and `max` come for free from [`Iterator`] as [`Amount`] is [`Ord`].
+pub trait AmountIterator<const DECIMALS: usize>: Iterator<Item = Amount<DECIMALS>> + Sized {
+    /// Sums all items, failing on overflow or on an item holding more than `DECIMALS` decimals,
+    /// rather than panicking or silently carrying the extra precision like [`std::iter::Sum`].
+    fn checked_sum(self) -> Result<Amount<DECIMALS>, AggregateError> {
+        self.map(Ok).try_sum()
+    }
+
+    /// Arithmetic mean rounded to `DECIMALS` decimals with `mode`, `None` when there are no items.
+    fn mean(self, mode: RoundingMode) -> Option<Amount<DECIMALS>> {
+        let (total, count) = self.fold((Amount::ZERO, 0_usize), |(total, count), amount| {
+            (total + amount, count + 1)
+        });
+
+        (count > 0).then(|| Amount::from(total.raw() / D128::from_usize(count)).round(mode))
+    }
+}
+
+impl<I, const DECIMALS: usize> AmountIterator<DECIMALS> for I where
+    I: Iterator<Item = Amount<DECIMALS>>
+{
+}
+
+/// Sums over fallible iterators, e.g. amounts parsed from a file.
+pub trait TryAmountIterator<const DECIMALS: usize, E>:
+    Iterator<Item = Result<Amount<DECIMALS>, E>> + Sized
+where
+    E: From<AggregateError>,
+{
+    /// Sums all items, stopping at the first error from the iterator, an overflow, or an item holding
+    /// more than `DECIMALS` decimals.
+    fn try_sum(self) -> Result<Amount<DECIMALS>, E> {
+        let mut total = Amount::ZERO;
+
+        for (index, amount) in self.enumerate() {
+            let amount = amount?;
+
+            if amount.decimals() > DECIMALS as i16 {
+                return Err(AggregateError::Scale {
+                    index,
+                    value: amount.to_string(),
+                    found: amount.decimals(),
+                    expected: DECIMALS,
+                }
+                .into());
+            }
+
+            total = total
+                .checked_add(amount)
+                .ok_or(AggregateError::Overflow { index })?;
+        }
+
+        Ok(total)
+    }
+}
+
+impl<I, E, const DECIMALS: usize> TryAmountIterator<DECIMALS, E> for I
+where
+    I: Iterator<Item = Result<Amount<DECIMALS>, E>>,
+    E: From<AggregateError>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AggregateError, AmountIterator, TryAmountIterator};
+    use crate::domain::value_type::amount::{Amount, ParseAmountError};
+    use crate::domain::value_type::rounding::RoundingMode;
+    use fastnum::D128;
+    use rstest::rstest;
+
+    fn amounts(values: &[&str]) -> Vec<Amount<2>> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    #[derive(Debug, thiserror::Error, PartialEq)]
+    enum ImportError {
+        #[error(transparent)]
+        Parse(#[from] ParseAmountError),
+        #[error(transparent)]
+        Aggregate(#[from] AggregateError),
+    }
+
+    #[test]
+    fn checked_sum() {
+        let values = amounts(&["548.15", "83.15", "805.28"]);
+
+        assert_eq!(
+            values.into_iter().checked_sum(),
+            Ok("1436.58".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn checked_sum_rejects_extra_decimals() {
+        let values = [Amount::<2>::new_scaled_i32(1234), Amount::new_f64(0.1)];
+
+        assert!(matches!(
+            values.into_iter().checked_sum(),
+            Err(AggregateError::Scale {
+                index: 1,
+                expected: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn checked_sum_overflow() {
+        let values = [
+            Amount::<0>::new_scaled_i32(1),
+            Amount::from(D128::MAX),
+            Amount::from(D128::MAX),
+        ];
+
+        assert_eq!(
+            values.into_iter().checked_sum(),
+            Err(AggregateError::Overflow { index: 1 })
+        );
+    }
+
+    #[test]
+    fn try_sum_fallible_iterator() {
+        let total: Result<Amount<2>, ImportError> = ["1.10", "2.20"]
+            .iter()
+            .map(|value| value.parse::<Amount<2>>().map_err(ImportError::from))
+            .try_sum();
+        assert_eq!(total, Ok(Amount::new_scaled_i32(330)));
+
+        let total: Result<Amount<2>, ImportError> = ["1.10", "2.205"]
+            .iter()
+            .map(|value| value.parse::<Amount<2>>().map_err(ImportError::from))
+            .try_sum();
+        assert!(matches!(
+            total,
+            Err(ImportError::Parse(ParseAmountError::Scale { .. }))
+        ));
+    }
+
+    #[rstest]
+    #[case(&["1.00", "2.00", "2.00"], RoundingMode::HalfUp, Some("1.67"))]
+    #[case(&["1.00", "2.00", "2.00"], RoundingMode::Down, Some("1.66"))]
+    #[case(&["0.01", "0.02"], RoundingMode::HalfEven, Some("0.02"))]
+    #[case(&["0.01", "0.02"], RoundingMode::HalfDown, Some("0.01"))]
+    #[case(&[], RoundingMode::HalfUp, None)]
+    fn mean(#[case] values: &[&str], #[case] mode: RoundingMode, #[case] expected: Option<&str>) {
+        let mean = amounts(values).into_iter().mean(mode);
+
+        assert_eq!(mean.map(|mean| mean.to_string()).as_deref(), expected);
+    }
+
+    #[test]
+    fn min_max() {
+        let values = amounts(&["548.15", "-83.15", "805.28"]);
+
+        assert_eq!(values.iter().min(), Some(&"-83.15".parse().unwrap()));
+        assert_eq!(values.iter().max(), Some(&"805.28".parse().unwrap()));
+    }
+}
@@ -0,0 +1,339 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::packed_amount::PackedAmount;
+use crate::domain::value_type::rounding::RoundingMode;
+use crate::domain::value_type::scaled_amount::{MinorUnitsError, ScaledAmount};
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum AmountVecError {
+    #[error("columns have different lengths: {left} and {right}")]
+    LengthMismatch { left: usize, right: usize },
+    #[error("amount at index {0} overflowed")]
+    Overflow(usize),
+    #[error("division by zero")]
+    DivisionByZero,
+}
+
+/// A column of amounts stored as [`PackedAmount`]s, 8 bytes each.
+///
+/// Arithmetic and aggregation work on the minor units directly, without going through [`Amount`].
+/// Bulk operations return a new column and fail as a whole, naming the first overflowing index.
+/// Sums are accumulated in [`i128`], so they cannot overflow.
+///
+/// Collect from an iterator of [`PackedAmount`]s, or of `Result`s to convert [`Amount`]s:
+/// `amounts.into_iter().map(PackedAmount::try_from).collect::<Result<AmountVec<2>, _>>()`.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct AmountVec<const DECIMALS: usize> {
+    minor_units: Vec<i64>,
+}
+
+impl<const DECIMALS: usize> AmountVec<DECIMALS> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            minor_units: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.minor_units.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.minor_units.is_empty()
+    }
+
+    /// Fails, leaving the column unchanged, if `amount` does not fit a [`PackedAmount`].
+    pub fn push(&mut self, amount: Amount<DECIMALS>) -> Result<(), MinorUnitsError> {
+        self.push_packed(PackedAmount::try_from(amount)?);
+        Ok(())
+    }
+
+    pub fn push_packed(&mut self, amount: PackedAmount<DECIMALS>) {
+        self.minor_units.push(amount.minor_units());
+    }
+
+    pub fn get(&self, index: usize) -> Option<Amount<DECIMALS>> {
+        self.get_packed(index).map(Amount::from)
+    }
+
+    pub fn get_packed(&self, index: usize) -> Option<PackedAmount<DECIMALS>> {
+        self.minor_units
+            .get(index)
+            .copied()
+            .map(PackedAmount::new_scaled_i64)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = PackedAmount<DECIMALS>> + '_ {
+        self.minor_units
+            .iter()
+            .copied()
+            .map(PackedAmount::new_scaled_i64)
+    }
+
+    pub fn as_minor_units(&self) -> &[i64] {
+        &self.minor_units
+    }
+
+    /// Element-wise `self + other`.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, AmountVecError> {
+        self.zip_with(other, i64::checked_add)
+    }
+
+    /// Element-wise `self - other`.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, AmountVecError> {
+        self.zip_with(other, i64::checked_sub)
+    }
+
+    /// Adds `amount` to every element, e.g. a fixed fee.
+    pub fn checked_add_scalar(
+        &self,
+        amount: PackedAmount<DECIMALS>,
+    ) -> Result<Self, AmountVecError> {
+        self.map(|minor_units| minor_units.checked_add(amount.minor_units()))
+    }
+
+    /// Multiplies every element by a whole number, e.g. a quantity.
+    pub fn checked_mul(&self, factor: i64) -> Result<Self, AmountVecError> {
+        self.map(|minor_units| minor_units.checked_mul(factor))
+    }
+
+    /// Multiplies every element by `numerator / denominator`, each rounded to `DECIMALS` decimals
+    /// with `mode`, e.g. by 19 / 100 for VAT. A zero `denominator` fails even for an empty column.
+    pub fn mul_ratio(
+        &self,
+        numerator: i64,
+        denominator: i64,
+        mode: RoundingMode,
+    ) -> Result<Self, AmountVecError> {
+        if denominator == 0 {
+            return Err(AmountVecError::DivisionByZero);
+        }
+
+        self.map(|minor_units| {
+            mode.div_i128(
+                i128::from(minor_units) * i128::from(numerator),
+                i128::from(denominator),
+            )
+            .and_then(|result| i64::try_from(result).ok())
+        })
+    }
+
+    pub fn sum(&self) -> ScaledAmount<DECIMALS> {
+        ScaledAmount::new_scaled_i128(self.minor_units.iter().copied().map(i128::from).sum())
+    }
+
+    pub fn min(&self) -> Option<PackedAmount<DECIMALS>> {
+        self.iter().min()
+    }
+
+    pub fn max(&self) -> Option<PackedAmount<DECIMALS>> {
+        self.iter().max()
+    }
+
+    /// Mean rounded to `DECIMALS` decimals with `mode`, `None` when the column is empty.
+    pub fn mean(&self, mode: RoundingMode) -> Option<ScaledAmount<DECIMALS>> {
+        let count = i64::try_from(self.len()).ok()?;
+        self.sum().checked_div(count, mode)
+    }
+
+    fn map(&self, f: impl Fn(i64) -> Option<i64>) -> Result<Self, AmountVecError> {
+        let minor_units = self
+            .minor_units
+            .iter()
+            .enumerate()
+            .map(|(index, &minor_units)| f(minor_units).ok_or(AmountVecError::Overflow(index)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { minor_units })
+    }
+
+    fn zip_with(
+        &self,
+        other: &Self,
+        f: impl Fn(i64, i64) -> Option<i64>,
+    ) -> Result<Self, AmountVecError> {
+        if self.len() != other.len() {
+            return Err(AmountVecError::LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+        let minor_units = self
+            .minor_units
+            .iter()
+            .zip(&other.minor_units)
+            .enumerate()
+            .map(|(index, (&left, &right))| f(left, right).ok_or(AmountVecError::Overflow(index)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { minor_units })
+    }
+}
+
+impl<const DECIMALS: usize> FromIterator<PackedAmount<DECIMALS>> for AmountVec<DECIMALS> {
+    fn from_iter<I: IntoIterator<Item = PackedAmount<DECIMALS>>>(iter: I) -> Self {
+        Self {
+            minor_units: iter
+                .into_iter()
+                .map(|amount| amount.minor_units())
+                .collect(),
+        }
+    }
+}
+
+impl<const DECIMALS: usize> Extend<PackedAmount<DECIMALS>> for AmountVec<DECIMALS> {
+    fn extend<I: IntoIterator<Item = PackedAmount<DECIMALS>>>(&mut self, iter: I) {
+        self.minor_units
+            .extend(iter.into_iter().map(|amount| amount.minor_units()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AmountVec, AmountVecError};
+    use crate::domain::aggregate::AmountIterator;
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::packed_amount::PackedAmount;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use crate::domain::value_type::scaled_amount::{MinorUnitsError, ScaledAmount};
+    use crate::utility::generate_data::monetary_value_generator::MonetaryValueGenerator;
+    use rstest::rstest;
+
+    fn column(values: &[&str]) -> AmountVec<2> {
+        values
+            .iter()
+            .map(|value| PackedAmount::try_from(value.parse::<Amount<2>>().unwrap()))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn strings(column: &AmountVec<2>) -> Vec<String> {
+        column.iter().map(|amount| amount.to_string()).collect()
+    }
+
+    #[test]
+    fn aggregates_match_amount() {
+        let amounts: Vec<Amount<2>> = MonetaryValueGenerator::<2>::builder()
+            .seed(39)
+            .negative_ratio(0.4)
+            .build()
+            .unwrap()
+            .amounts()
+            .take(10_000)
+            .collect();
+        let column = amounts
+            .iter()
+            .copied()
+            .map(PackedAmount::try_from)
+            .collect::<Result<AmountVec<2>, _>>()
+            .unwrap();
+
+        assert_eq!(column.len(), amounts.len());
+        assert_eq!(
+            Amount::from(column.sum()),
+            amounts.iter().copied().checked_sum().unwrap()
+        );
+        assert_eq!(
+            column.min().map(Amount::from),
+            amounts.iter().copied().min()
+        );
+        assert_eq!(
+            column.max().map(Amount::from),
+            amounts.iter().copied().max()
+        );
+        assert!(column.iter().map(Amount::from).eq(amounts));
+    }
+
+    #[test]
+    fn sum_does_not_overflow() {
+        let column: AmountVec<2> = [PackedAmount::MAX; 3].into_iter().collect();
+
+        assert_eq!(
+            column.sum(),
+            ScaledAmount::new_scaled_i128(3 * i128::from(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn bulk_arithmetic() {
+        let prices = column(&["10.00", "-2.50", "0.99"]);
+        let fees = column(&["0.25", "0.25", "0.01"]);
+
+        assert_eq!(
+            strings(&prices.checked_add(&fees).unwrap()),
+            ["10.25", "-2.25", "1.00"]
+        );
+        assert_eq!(
+            strings(&prices.checked_sub(&fees).unwrap()),
+            ["9.75", "-2.75", "0.98"]
+        );
+        assert_eq!(
+            strings(
+                &prices
+                    .checked_add_scalar(PackedAmount::new_scaled_i64(1))
+                    .unwrap()
+            ),
+            ["10.01", "-2.49", "1.00"]
+        );
+        assert_eq!(
+            strings(&prices.checked_mul(3).unwrap()),
+            ["30.00", "-7.50", "2.97"]
+        );
+        assert_eq!(
+            strings(&prices.mul_ratio(19, 100, RoundingMode::HalfEven).unwrap()),
+            ["1.90", "-0.48", "0.19"]
+        );
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfEven, Some("2.83"))]
+    #[case(RoundingMode::Up, Some("2.84"))]
+    #[case(RoundingMode::Down, Some("2.83"))]
+    fn mean(#[case] mode: RoundingMode, #[case] expected: Option<&str>) {
+        let column = column(&["10.00", "-2.50", "1.00"]);
+
+        assert_eq!(
+            column.mean(mode).map(|mean| mean.to_string()).as_deref(),
+            expected
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let column = AmountVec::<2>::new();
+
+        assert!(column.is_empty());
+        assert_eq!(column.sum(), ScaledAmount::ZERO);
+        assert_eq!(column.min(), None);
+        assert_eq!(column.mean(RoundingMode::HalfEven), None);
+    }
+
+    #[test]
+    fn errors() {
+        let column = column(&["1.00", "92233720368547758.07"]);
+
+        assert_eq!(
+            column.checked_add(&AmountVec::new()),
+            Err(AmountVecError::LengthMismatch { left: 2, right: 0 })
+        );
+        assert_eq!(column.checked_mul(2), Err(AmountVecError::Overflow(1)));
+        assert_eq!(
+            column.mul_ratio(1, 0, RoundingMode::HalfEven),
+            Err(AmountVecError::DivisionByZero)
+        );
+        assert_eq!(
+            AmountVec::<2>::new().mul_ratio(1, 0, RoundingMode::HalfEven),
+            Err(AmountVecError::DivisionByZero)
+        );
+
+        let mut column = column;
+        assert!(matches!(
+            column.push(Amount::new_f64(0.1)),
+            Err(MinorUnitsError::Scale { .. })
+        ));
+        assert_eq!(column.len(), 2);
+        assert_eq!(column.get(1), Some("92233720368547758.07".parse().unwrap()));
+        assert_eq!(column.get(2), None);
+    }
+}
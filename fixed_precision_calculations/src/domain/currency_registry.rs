@@ -0,0 +1,260 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::rounding::RoundingMode;
+use fastnum::{D128, dec128};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Registry of the currencies known to the crate, with their ISO 4217 minor units and cash-rounding rules.
+pub static CURRENCIES: LazyLock<CurrencyRegistry> = LazyLock::new(CurrencyRegistry::default);
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum CurrencyRegistryError {
+    #[error("currency {0} is not registered")]
+    UnknownCurrency(Currency),
+}
+
+/// How a payment is settled, which decides the rounding that applies to it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Tender {
+    /// Card and bank payments, settled to the minor unit (e.g. 0.01 CHF).
+    Card,
+    /// Cash payments, settled to the smallest coin in circulation (e.g. 0.05 CHF).
+    Cash,
+}
+
+/// Rounds cash totals to the smallest coin in circulation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CashRounding {
+    /// Smallest payable cash amount, e.g. 0.05 for CHF.
+    pub increment: D128,
+    #[serde(default)]
+    pub mode: RoundingMode,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CurrencySpec {
+    pub currency: Currency,
+    /// Number of decimals of the minor unit, e.g. 2 for EUR and 0 for JPY.
+    pub minor_units: u8,
+    /// `None` when cash is settled to the minor unit like any other payment.
+    #[serde(default)]
+    pub cash_rounding: Option<CashRounding>,
+}
+
+/// Amount to be paid after rounding, together with what was rounded away.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Settlement<const DECIMALS: usize> {
+    pub payable: Amount<DECIMALS>,
+    /// `payable - original`, to be booked to a rounding-differences account.
+    pub rounding_difference: Amount<DECIMALS>,
+}
+
+impl CashRounding {
+    pub fn apply<const DECIMALS: usize>(&self, amount: Amount<DECIMALS>) -> Settlement<DECIMALS> {
+        Settlement::new(
+            amount,
+            amount.round_to_increment(Amount::from(self.increment), self.mode),
+        )
+    }
+}
+
+impl<const DECIMALS: usize> Settlement<DECIMALS> {
+    fn new(original: Amount<DECIMALS>, payable: Amount<DECIMALS>) -> Self {
+        Self {
+            payable,
+            rounding_difference: payable - original,
+        }
+    }
+}
+
+impl CurrencySpec {
+    pub const fn new(currency: Currency, minor_units: u8) -> Self {
+        Self {
+            currency,
+            minor_units,
+            cash_rounding: None,
+        }
+    }
+
+    pub const fn with_cash_rounding(mut self, increment: D128, mode: RoundingMode) -> Self {
+        self.cash_rounding = Some(CashRounding { increment, mode });
+        self
+    }
+
+    /// The minor unit as an amount, e.g. 0.01 for EUR.
+    pub fn minor_unit(&self) -> D128 {
+        D128::ONE / D128::TEN.powi(self.minor_units as i32)
+    }
+
+    /// Rounds `amount` to what can actually be paid with the given tender.
+    pub fn settle<const DECIMALS: usize>(
+        &self,
+        amount: Amount<DECIMALS>,
+        tender: Tender,
+    ) -> Settlement<DECIMALS> {
+        match (tender, self.cash_rounding) {
+            (Tender::Cash, Some(cash_rounding)) => cash_rounding.apply(amount),
+            _ => Settlement::new(
+                amount,
+                amount.round_to_increment(Amount::from(self.minor_unit()), RoundingMode::HalfUp),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyRegistry {
+    specs: HashMap<Currency, CurrencySpec>,
+}
+
+impl CurrencyRegistry {
+    pub fn empty() -> Self {
+        Self {
+            specs: HashMap::new(),
+        }
+    }
+
+    /// Adds or replaces a currency, returning the previous spec if any.
+    pub fn register(&mut self, spec: CurrencySpec) -> Option<CurrencySpec> {
+        self.specs.insert(spec.currency, spec)
+    }
+
+    pub fn get(&self, currency: Currency) -> Result<&CurrencySpec, CurrencyRegistryError> {
+        self.specs
+            .get(&currency)
+            .ok_or(CurrencyRegistryError::UnknownCurrency(currency))
+    }
+
+    pub fn settle<const DECIMALS: usize>(
+        &self,
+        currency: Currency,
+        amount: Amount<DECIMALS>,
+        tender: Tender,
+    ) -> Result<Settlement<DECIMALS>, CurrencyRegistryError> {
+        Ok(self.get(currency)?.settle(amount, tender))
+    }
+}
+
+impl Default for CurrencyRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+
+        [
+            CurrencySpec::new(Currency::AUD, 2)
+                .with_cash_rounding(dec128!(0.05), RoundingMode::HalfUp),
+            CurrencySpec::new(Currency::CAD, 2)
+                .with_cash_rounding(dec128!(0.05), RoundingMode::HalfUp),
+            CurrencySpec::new(Currency::CHF, 2)
+                .with_cash_rounding(dec128!(0.05), RoundingMode::HalfUp),
+            // The smallest Danish coin in circulation is 50 øre.
+            CurrencySpec::new(Currency::DKK, 2)
+                .with_cash_rounding(dec128!(0.50), RoundingMode::HalfUp),
+            CurrencySpec::new(Currency::EUR, 2),
+            CurrencySpec::new(Currency::GBP, 2),
+            CurrencySpec::new(Currency::JPY, 0),
+            CurrencySpec::new(Currency::NOK, 2)
+                .with_cash_rounding(dec128!(1), RoundingMode::HalfUp),
+            CurrencySpec::new(Currency::NZD, 2)
+                .with_cash_rounding(dec128!(0.10), RoundingMode::HalfUp),
+            CurrencySpec::new(Currency::SEK, 2)
+                .with_cash_rounding(dec128!(1), RoundingMode::HalfUp),
+            CurrencySpec::new(Currency::USD, 2),
+        ]
+        .into_iter()
+        .for_each(|spec| {
+            registry.register(spec);
+        });
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CURRENCIES, CurrencyRegistry, CurrencyRegistryError, CurrencySpec, Settlement, Tender,
+    };
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use fastnum::dec128;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Currency::CHF, "12.32", Tender::Cash, "12.30", "-0.02")]
+    #[case(Currency::CHF, "12.33", Tender::Cash, "12.35", "0.02")]
+    #[case(Currency::CHF, "12.33", Tender::Card, "12.33", "0.00")]
+    #[case(Currency::SEK, "99.50", Tender::Cash, "100.00", "0.50")]
+    #[case(Currency::SEK, "99.49", Tender::Cash, "99.00", "-0.49")]
+    #[case(Currency::DKK, "10.24", Tender::Cash, "10.00", "-0.24")]
+    #[case(Currency::EUR, "10.24", Tender::Cash, "10.24", "0.00")]
+    fn settle_amount2(
+        #[case] currency: Currency,
+        #[case] amount: &str,
+        #[case] tender: Tender,
+        #[case] payable: &str,
+        #[case] difference: &str,
+    ) {
+        let settlement: Settlement<2> = CURRENCIES
+            .settle(currency, amount.parse().unwrap(), tender)
+            .unwrap();
+
+        assert_eq!(settlement.payable.to_string(), payable);
+        assert_eq!(settlement.rounding_difference.to_string(), difference);
+    }
+
+    #[test]
+    fn card_settles_to_minor_unit() {
+        let amount: Amount<4> = "12.3349".parse().unwrap();
+        let settlement = CURRENCIES
+            .settle(Currency::CHF, amount, Tender::Card)
+            .unwrap();
+
+        assert_eq!(settlement.payable, "12.33".parse().unwrap());
+        assert_eq!(settlement.rounding_difference, "-0.0049".parse().unwrap());
+
+        let settlement = CURRENCIES
+            .settle(Currency::JPY, amount, Tender::Card)
+            .unwrap();
+        assert_eq!(settlement.payable, "12".parse().unwrap());
+    }
+
+    #[test]
+    fn custom_registry() {
+        let mut registry = CurrencyRegistry::empty();
+        assert_eq!(
+            registry.get(Currency::CHF),
+            Err(CurrencyRegistryError::UnknownCurrency(Currency::CHF))
+        );
+
+        registry.register(
+            CurrencySpec::new(Currency::CHF, 2)
+                .with_cash_rounding(dec128!(0.10), RoundingMode::Down),
+        );
+        let settlement = registry
+            .settle(
+                Currency::CHF,
+                Amount::<2>::new_scaled_i32(1239),
+                Tender::Cash,
+            )
+            .unwrap();
+
+        assert_eq!(settlement.payable, Amount::new_scaled_i32(1230));
+    }
+
+    #[test]
+    fn deserialise_spec() {
+        let spec: CurrencySpec = serde_json::from_str(
+            r#"{ "currency": "CHF", "minor_units": 2, "cash_rounding": { "increment": "0.05" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            spec,
+            CurrencySpec::new(Currency::CHF, 2)
+                .with_cash_rounding(dec128!(0.05), RoundingMode::HalfUp)
+        );
+    }
+}
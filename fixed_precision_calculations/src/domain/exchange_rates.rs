@@ -0,0 +1,56 @@
+use crate::domain::value_type::currency::Currency;
+use fastnum::D128;
+use std::collections::HashMap;
+
+/// Table of exchange rates, quoted as units of the target currency per unit of the source currency.
+///
+/// Only quoted pairs are known, i.e. an inverse rate is never derived, as that would need an inexact
+/// division.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExchangeRates {
+    rates: HashMap<(Currency, Currency), D128>,
+}
+
+impl ExchangeRates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rate from `from` to `to`, e.g. `set(EUR, CHF, 0.94)` for 1 EUR = 0.94 CHF.
+    pub fn set(&mut self, from: Currency, to: Currency, rate: D128) -> Option<D128> {
+        self.rates.insert((from, to), rate)
+    }
+
+    pub fn with_rate(mut self, from: Currency, to: Currency, rate: D128) -> Self {
+        self.set(from, to, rate);
+        self
+    }
+
+    /// Rate from `from` to `to`, which is always 1 for the same currency.
+    pub fn rate(&self, from: Currency, to: Currency) -> Option<D128> {
+        if from == to {
+            return Some(D128::ONE);
+        }
+
+        self.rates.get(&(from, to)).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExchangeRates;
+    use crate::domain::value_type::currency::Currency;
+    use fastnum::{D128, dec128};
+
+    #[test]
+    fn quoted_rates_only() {
+        let rates = ExchangeRates::new().with_rate(Currency::EUR, Currency::CHF, dec128!(0.94));
+
+        assert_eq!(
+            rates.rate(Currency::EUR, Currency::CHF),
+            Some(dec128!(0.94))
+        );
+        assert_eq!(rates.rate(Currency::CHF, Currency::EUR), None);
+        assert_eq!(rates.rate(Currency::SEK, Currency::SEK), Some(D128::ONE));
+    }
+}
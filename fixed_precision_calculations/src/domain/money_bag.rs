@@ -0,0 +1,230 @@
+use crate::domain::exchange_rates::ExchangeRates;
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::money::Money;
+use crate::domain::value_type::rounding::RoundingMode;
+use std::collections::BTreeMap;
+
+/// Holds balances in many currencies at once, e.g. a wallet or portfolio.
+///
+/// Amounts of different currencies are never added together; each currency keeps its own total.
+#[derive(Debug, Clone, Default)]
+pub struct MoneyBag<const DECIMALS: usize> {
+    totals: BTreeMap<Currency, Amount<DECIMALS>>,
+}
+
+/// Result of converting a [`MoneyBag`] into a single reporting currency.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Conversion<const DECIMALS: usize> {
+    /// Sum of all positions that had a rate, rounded once after summing.
+    pub total: Money<DECIMALS>,
+    /// Positions left out of `total` because the rate table had no rate for them.
+    pub missing_rates: Vec<Money<DECIMALS>>,
+}
+
+impl<const DECIMALS: usize> MoneyBag<DECIMALS> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, money: Money<DECIMALS>) {
+        *self.totals.entry(money.currency()).or_default() += money.amount();
+    }
+
+    pub fn sub(&mut self, money: Money<DECIMALS>) {
+        self.add(-money);
+    }
+
+    /// Total held in `currency`, zero if there is none.
+    pub fn get(&self, currency: Currency) -> Money<DECIMALS> {
+        let amount = self.totals.get(&currency).copied().unwrap_or_default();
+
+        Money::new(amount, currency)
+    }
+
+    /// Non-zero positions, ordered by currency code.
+    pub fn iter(&self) -> impl Iterator<Item = Money<DECIMALS>> + '_ {
+        self.totals
+            .iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(currency, amount)| Money::new(*amount, *currency))
+    }
+
+    /// `true` when all positions are zero.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Converts all positions into `target` using `rates`.
+    ///
+    /// Positions without a rate are reported in [`Conversion::missing_rates`] instead of failing the
+    /// whole conversion.
+    pub fn convert(
+        &self,
+        target: Currency,
+        rates: &ExchangeRates,
+        mode: RoundingMode,
+    ) -> Conversion<DECIMALS> {
+        let mut total = Amount::ZERO;
+        let mut missing_rates = Vec::new();
+
+        for money in self.iter() {
+            match rates.rate(money.currency(), target) {
+                Some(rate) => total += Amount::from(money.amount().raw() * rate),
+                None => missing_rates.push(money),
+            }
+        }
+
+        Conversion {
+            total: Money::new(total.round(mode), target),
+            missing_rates,
+        }
+    }
+}
+
+/// Bags are equal when they hold the same non-zero positions.
+impl<const DECIMALS: usize> PartialEq for MoneyBag<DECIMALS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<const DECIMALS: usize> Eq for MoneyBag<DECIMALS> {}
+
+impl<const DECIMALS: usize> std::ops::AddAssign<Money<DECIMALS>> for MoneyBag<DECIMALS> {
+    fn add_assign(&mut self, rhs: Money<DECIMALS>) {
+        self.add(rhs);
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::SubAssign<Money<DECIMALS>> for MoneyBag<DECIMALS> {
+    fn sub_assign(&mut self, rhs: Money<DECIMALS>) {
+        self.sub(rhs);
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::AddAssign<&MoneyBag<DECIMALS>> for MoneyBag<DECIMALS> {
+    fn add_assign(&mut self, rhs: &MoneyBag<DECIMALS>) {
+        rhs.iter().for_each(|money| self.add(money));
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::SubAssign<&MoneyBag<DECIMALS>> for MoneyBag<DECIMALS> {
+    fn sub_assign(&mut self, rhs: &MoneyBag<DECIMALS>) {
+        rhs.iter().for_each(|money| self.sub(money));
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::Add<&MoneyBag<DECIMALS>> for MoneyBag<DECIMALS> {
+    type Output = MoneyBag<DECIMALS>;
+
+    fn add(mut self, rhs: &MoneyBag<DECIMALS>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::Sub<&MoneyBag<DECIMALS>> for MoneyBag<DECIMALS> {
+    type Output = MoneyBag<DECIMALS>;
+
+    fn sub(mut self, rhs: &MoneyBag<DECIMALS>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const DECIMALS: usize> Extend<Money<DECIMALS>> for MoneyBag<DECIMALS> {
+    fn extend<I: IntoIterator<Item = Money<DECIMALS>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|money| self.add(money));
+    }
+}
+
+impl<const DECIMALS: usize> FromIterator<Money<DECIMALS>> for MoneyBag<DECIMALS> {
+    fn from_iter<I: IntoIterator<Item = Money<DECIMALS>>>(iter: I) -> Self {
+        let mut bag = Self::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoneyBag;
+    use crate::domain::exchange_rates::ExchangeRates;
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use crate::domain::value_type::money::Money;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use fastnum::dec128;
+
+    fn money(amount: &str, currency: Currency) -> Money<2> {
+        Money::new(amount.parse().unwrap(), currency)
+    }
+
+    #[test]
+    fn accumulates_per_currency() {
+        let bag: MoneyBag<2> = [
+            money("10.00", Currency::EUR),
+            money("5.50", Currency::CHF),
+            money("2.25", Currency::EUR),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(bag.get(Currency::EUR), money("12.25", Currency::EUR));
+        assert_eq!(bag.get(Currency::CHF), money("5.50", Currency::CHF));
+        assert_eq!(bag.get(Currency::SEK), Money::zero(Currency::SEK));
+    }
+
+    #[test]
+    fn iterates_non_zero_positions() {
+        let mut bag = MoneyBag::<2>::new();
+        bag += money("10.00", Currency::EUR);
+        bag += money("5.50", Currency::CHF);
+        bag -= money("10.00", Currency::EUR);
+
+        let positions: Vec<String> = bag.iter().map(|money| money.to_string()).collect();
+        assert_eq!(positions, ["5.50 CHF"]);
+
+        bag -= money("5.50", Currency::CHF);
+        assert!(bag.is_empty());
+    }
+
+    #[test]
+    fn add_and_subtract_bags() {
+        let lhs: MoneyBag<2> = [money("10.00", Currency::EUR), money("1.00", Currency::CHF)]
+            .into_iter()
+            .collect();
+        let rhs: MoneyBag<2> = [money("2.50", Currency::EUR), money("3.00", Currency::SEK)]
+            .into_iter()
+            .collect();
+
+        let sum = lhs.clone() + &rhs;
+        assert_eq!(sum.get(Currency::EUR), money("12.50", Currency::EUR));
+        assert_eq!(sum.get(Currency::SEK), money("3.00", Currency::SEK));
+
+        let difference = sum - &rhs;
+        assert_eq!(difference, lhs);
+    }
+
+    #[test]
+    fn convert_reports_missing_rates() {
+        let bag: MoneyBag<2> = [
+            money("100.00", Currency::EUR),
+            money("10.00", Currency::CHF),
+            money("50.00", Currency::SEK),
+        ]
+        .into_iter()
+        .collect();
+        let rates = ExchangeRates::new().with_rate(Currency::EUR, Currency::CHF, dec128!(0.9415));
+
+        let conversion = bag.convert(Currency::CHF, &rates, RoundingMode::HalfEven);
+
+        // 100.00 EUR * 0.9415 + 10.00 CHF
+        assert_eq!(
+            conversion.total,
+            Money::new(Amount::new_scaled_i32(10415), Currency::CHF)
+        );
+        assert_eq!(conversion.missing_rates, [money("50.00", Currency::SEK)]);
+    }
+}
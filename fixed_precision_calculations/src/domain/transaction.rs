@@ -0,0 +1,131 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::money::Money;
+use crate::domain::value_type::uid::Uid;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// Money leaving the account.
+    Debit,
+    /// Money entering the account.
+    Credit,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    Groceries,
+    Dining,
+    Transport,
+    Travel,
+    Utilities,
+    Entertainment,
+    Shopping,
+    Salary,
+    Refund,
+    Transfer,
+}
+
+/// A booked account transaction.
+///
+/// `amount` is always the non-negative magnitude; the sign follows from `direction`, see
+/// [`Transaction::signed_amount`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    pub id: Uid<Transaction>,
+    pub booked_at: DateTime<Utc>,
+    pub merchant: String,
+    pub category: Category,
+    pub currency: Currency,
+    pub amount: Amount<2>,
+    pub direction: Direction,
+}
+
+impl Category {
+    pub const DEBITS: [Self; 7] = [
+        Self::Groceries,
+        Self::Dining,
+        Self::Transport,
+        Self::Travel,
+        Self::Utilities,
+        Self::Entertainment,
+        Self::Shopping,
+    ];
+
+    pub const CREDITS: [Self; 3] = [Self::Salary, Self::Refund, Self::Transfer];
+}
+
+impl Transaction {
+    /// Amount as seen from the account, i.e. negative for debits.
+    pub fn signed_amount(&self) -> Amount<2> {
+        match self.direction {
+            Direction::Debit => -self.amount,
+            Direction::Credit => self.amount,
+        }
+    }
+
+    pub fn money(&self) -> Money<2> {
+        Money::new(self.signed_amount(), self.currency)
+    }
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Debit => "debit",
+            Self::Credit => "credit",
+        })
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Groceries => "groceries",
+            Self::Dining => "dining",
+            Self::Transport => "transport",
+            Self::Travel => "travel",
+            Self::Utilities => "utilities",
+            Self::Entertainment => "entertainment",
+            Self::Shopping => "shopping",
+            Self::Salary => "salary",
+            Self::Refund => "refund",
+            Self::Transfer => "transfer",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Category, Direction, Transaction};
+    use crate::domain::value_type::currency::Currency;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn serde_transaction() {
+        let transaction = Transaction {
+            id: "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap(),
+            booked_at: Utc.with_ymd_and_hms(2025, 3, 1, 9, 30, 0).unwrap(),
+            merchant: "Acme".to_string(),
+            category: Category::Groceries,
+            currency: Currency::EUR,
+            amount: "12.30".parse().unwrap(),
+            direction: Direction::Debit,
+        };
+
+        let json = serde_json::to_string(&transaction).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"id":"67e55044-10b1-426f-9247-bb680e5fe0c8","booked_at":"2025-03-01T09:30:00Z","merchant":"Acme","category":"groceries","currency":"EUR","amount":"12.30","direction":"debit"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Transaction>(&json).unwrap(),
+            transaction
+        );
+        assert_eq!(transaction.signed_amount().to_string(), "-12.30");
+    }
+}
@@ -1,93 +1,364 @@
-use fastnum::D128;
+use crate::domain::value_type::monetary_amount::MonetaryAmount;
+use crate::domain::value_type::rounding::RoundingMode;
+use fastnum::decimal::{Context, Decimal};
 use std::fmt::Formatter;
+use std::str::FromStr;
 
+/// A decimal amount with `DECIMALS` decimals, backed by a fastnum [`Decimal`] of `N` 64-bit words.
+///
+/// `N` defaults to 2, a [`fastnum::D128`] (~38 digits), which is plenty for fiat. Amounts with many
+/// decimals and large magnitudes, e.g. token balances in wei (18 decimals), can opt into
+/// [`Amount256`] or [`Amount512`]. Widening is infallible (`From`); narrowing is checked (`TryFrom`).
 #[derive(Debug, Clone, Copy, Eq, Ord, PartialOrd, PartialEq, Hash, Default)]
-pub struct Amount<const DECIMALS: usize>(D128);
+pub struct Amount<const DECIMALS: usize, const N: usize = 2>(Decimal<N>);
+
+/// An [`Amount`] backed by a [`fastnum::D256`] (~77 digits).
+pub type Amount256<const DECIMALS: usize> = Amount<DECIMALS, 4>;
+
+/// An [`Amount`] backed by a [`fastnum::D512`] (~154 digits).
+pub type Amount512<const DECIMALS: usize> = Amount<DECIMALS, 8>;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("{value} does not fit a {bits}-bit decimal")]
+pub struct NarrowAmountError {
+    pub value: String,
+    pub bits: usize,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ParseAmountError {
+    #[error("invalid decimal '{0}'")]
+    Invalid(String),
+    #[error("'{input}' has {found} decimals, expected at most {expected}")]
+    Scale {
+        input: String,
+        found: i16,
+        expected: usize,
+    },
+}
+
+impl<const DECIMALS: usize, const N: usize> Amount<DECIMALS, N> {
+    pub const ZERO: Self = Self::new_scaled_i32(0);
 
-impl<const DECIMALS: usize> Amount<DECIMALS> {
     /// Treats the input as a scaled integer (e.g. 1234 → 12.34)
     pub const fn new_scaled_i32(inner: i32) -> Self {
-        Self(D128::from_i32(inner).div(D128::from_i32(10_i32).pow(D128::from_usize(DECIMALS))))
+        Self(
+            Decimal::<N>::from_i32(inner)
+                .div(Decimal::<N>::from_i32(10_i32).pow(Decimal::<N>::from_usize(DECIMALS))),
+        )
     }
 
     /// Treats the input as a scaled integer (e.g. 1234 → 12.34)
     pub const fn new_scaled_i64(inner: i64) -> Self {
-        Self(D128::from_i64(inner).div(D128::from_i64(10_i64).pow(D128::from_usize(DECIMALS))))
+        Self(
+            Decimal::<N>::from_i64(inner)
+                .div(Decimal::<N>::from_i64(10_i64).pow(Decimal::<N>::from_usize(DECIMALS))),
+        )
     }
 
     pub const fn new_f64(inner: f64) -> Self {
-        Self(D128::from_f64(inner))
+        Self(Decimal::<N>::from_f64(inner))
     }
 
     pub const fn is_zero(&self) -> bool {
         self.0.is_zero()
     }
+
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub const fn raw(&self) -> Decimal<N> {
+        self.0
+    }
+
+    pub const fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Rounds to exactly `DECIMALS` decimal places using the given rounding mode (e.g. 12.345 → 12.35
+    /// with [`RoundingMode::HalfUp`]).
+    pub fn round(self, mode: RoundingMode) -> Self {
+        Self(
+            self.0
+                .with_rounding_mode(mode.into())
+                .round(DECIMALS as i16)
+                .with_ctx(Context::default()),
+        )
+    }
+
+    /// Adds `rhs`, returning `None` if the result overflows or would need more digits than
+    /// the backing [`Decimal`] can hold.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::checked(Self::untrapped(self.0) + Self::untrapped(rhs.0))
+    }
+
+    /// Subtracts `rhs`, returning `None` if the result overflows or would need more digits than
+    /// the backing [`Decimal`] can hold.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::checked(Self::untrapped(self.0) - Self::untrapped(rhs.0))
+    }
+
+    /// Rebuilds `value` from its parts, dropping any signals (e.g. inexact, after a division) carried
+    /// over from earlier operations, so that only those raised by the next operation are seen.
+    const fn untrapped(value: Decimal<N>) -> Decimal<N> {
+        Decimal::<N>::from_parts(
+            value.digits(),
+            -(value.fractional_digits_count() as i32),
+            value.sign(),
+            Context::default().without_traps(),
+        )
+    }
+
+    fn checked(value: Decimal<N>) -> Option<Self> {
+        if !value.is_finite() || value.is_op_overflow() || value.is_op_inexact() {
+            return None;
+        }
+
+        Some(Self(value.with_ctx(Context::default())))
+    }
+
+    /// The amount as an integer number of minor units (e.g. 12.34 → 1234), `None` if it holds more
+    /// than `DECIMALS` decimals or does not fit an [`i64`].
+    pub fn to_minor_units(&self) -> Option<i64> {
+        self.minor_units()?.to_i64().ok()
+    }
+
+    /// The amount as an [`i128`] number of minor units, `None` if it holds more than `DECIMALS`
+    /// decimals or does not fit.
+    pub fn to_minor_units_i128(&self) -> Option<i128> {
+        self.minor_units()?.to_i128().ok()
+    }
+
+    /// The amount times 10^`DECIMALS`, computed without trapping so that overflow is `None` rather
+    /// than a panic.
+    fn minor_units(&self) -> Option<Decimal<N>> {
+        if self.decimals() > DECIMALS as i16 {
+            return None;
+        }
+
+        let unit = Decimal::<N>::TEN
+            .powi(DECIMALS as i32)
+            .with_ctx(Context::default().without_traps());
+        let minor_units = Self::untrapped(self.0).mul(unit);
+
+        (minor_units.is_finite() && !minor_units.is_op_overflow()).then_some(minor_units)
+    }
+
+    /// Number of decimals actually held, which can exceed `DECIMALS` (e.g. after [`Amount::new_f64`]).
+    pub const fn decimals(&self) -> i16 {
+        self.0.fractional_digits_count()
+    }
+
+    /// Rounds to a multiple of `increment` using the given rounding mode, e.g. 12.34 → 12.35 with an
+    /// increment of 0.05 and [`RoundingMode::HalfUp`], as used for cash rounding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `increment` is zero.
+    pub fn round_to_increment(self, increment: Self, mode: RoundingMode) -> Self {
+        assert!(
+            !increment.is_zero(),
+            "Attempt to round Amount to a zero increment"
+        );
+
+        let increment = increment.0.abs();
+        let steps = (self.0 / increment)
+            .with_rounding_mode(mode.into())
+            .round(0)
+            .with_ctx(Context::default());
+
+        Self((steps * increment).rescale(DECIMALS as i16))
+    }
+
+    /// The same amount backed by a [`Decimal`] of `M` words, `None` if it needs more digits than
+    /// those hold. Widening always succeeds.
+    pub fn checked_resize<const M: usize>(self) -> Option<Amount<DECIMALS, M>> {
+        let resized = Amount::<DECIMALS, M>::untrapped(Self::untrapped(self.0).transmute());
+        let round_trip: Decimal<N> = Amount::<DECIMALS, M>::untrapped(resized).transmute();
+
+        (round_trip == self.0 && round_trip.fractional_digits_count() == self.decimals())
+            .then(|| Amount(resized.with_ctx(Context::default())))
+    }
+}
+
+/// Infallible widening and checked narrowing between the [`Amount`] widths.
+macro_rules! resize_impls {
+    ($(($narrow:literal, $wide:literal)),*) => {
+        $(
+            impl<const DECIMALS: usize> From<Amount<DECIMALS, $narrow>> for Amount<DECIMALS, $wide> {
+                fn from(value: Amount<DECIMALS, $narrow>) -> Self {
+                    value
+                        .checked_resize()
+                        .expect("widening an Amount is lossless")
+                }
+            }
+
+            impl<const DECIMALS: usize> TryFrom<Amount<DECIMALS, $wide>> for Amount<DECIMALS, $narrow> {
+                type Error = NarrowAmountError;
+
+                fn try_from(value: Amount<DECIMALS, $wide>) -> Result<Self, Self::Error> {
+                    value.checked_resize().ok_or_else(|| NarrowAmountError {
+                        value: value.to_string(),
+                        bits: $narrow * 64,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+resize_impls!((2, 4), (2, 8), (4, 8));
+
+impl<const DECIMALS: usize, const N: usize> FromStr for Amount<DECIMALS, N> {
+    type Err = ParseAmountError;
+
+    /// Parses a plain decimal string (`[+-]digits[.digits]`) exactly, without going through [`f64`]
+    /// (e.g. "12.3" → 12.30). Exponents such as "1e3" are rejected, as in `xs:decimal`.
+    ///
+    /// Inputs with more than `DECIMALS` significant decimals are rejected rather than silently
+    /// rounded; trailing zeros are not significant (e.g. "12.340" is a valid `Amount<2>`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseAmountError::Invalid(s.to_string());
+        let text = s.trim();
+        let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, "0"));
+        let digits =
+            |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+        if !digits(integer) || !digits(fraction) {
+            return Err(invalid());
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > DECIMALS {
+            return Err(ParseAmountError::Scale {
+                input: s.to_string(),
+                found: fraction.len() as i16,
+                expected: DECIMALS,
+            });
+        }
+
+        let sign = if text.starts_with('-') { "-" } else { "" };
+        let exact = match fraction {
+            "" => format!("{sign}{integer}"),
+            fraction => format!("{sign}{integer}.{fraction}"),
+        };
+        let value = Decimal::<N>::from_str(&exact, Context::default().without_traps())
+            .map_err(|_| invalid())?
+            .rescale(DECIMALS as i16);
+
+        // Values with more digits than the backing Decimal holds cannot be rescaled exactly.
+        if value.is_op_inexact() || value.fractional_digits_count() != DECIMALS as i16 {
+            return Err(invalid());
+        }
+        Ok(Self(value.with_ctx(Context::default())))
+    }
+}
+
+impl<const DECIMALS: usize, const N: usize> serde::Serialize for Amount<DECIMALS, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
 }
 
-impl<const DECIMALS: usize> From<i32> for Amount<DECIMALS> {
+impl<'de, const DECIMALS: usize, const N: usize> serde::Deserialize<'de> for Amount<DECIMALS, N> {
+    /// Accepts decimal strings (e.g. "12.34") and integers, which are read as whole units (e.g. 12 → 12.00).
+    ///
+    /// Floats are rejected, as they have already lost precision by the time they reach us.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<const DECIMALS: usize, const N: usize>;
+
+        impl<const DECIMALS: usize, const N: usize> serde::de::Visitor<'_> for Visitor<DECIMALS, N> {
+            type Value = Amount<DECIMALS, N>;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                write!(
+                    f,
+                    "a decimal string with at most {DECIMALS} decimals, or an integer"
+                )
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(Amount(Decimal::<N>::from_i64(v).rescale(DECIMALS as i16)))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Amount(Decimal::<N>::from_u64(v).rescale(DECIMALS as i16)))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor::<DECIMALS, N>)
+    }
+}
+
+impl<const DECIMALS: usize, const N: usize> From<i32> for Amount<DECIMALS, N> {
     fn from(value: i32) -> Self {
         Self::new_scaled_i32(value)
     }
 }
 
-impl<const DECIMALS: usize> From<i64> for Amount<DECIMALS> {
+impl<const DECIMALS: usize, const N: usize> From<i64> for Amount<DECIMALS, N> {
     fn from(value: i64) -> Self {
         Self::new_scaled_i64(value)
     }
 }
 
-impl<const DECIMALS: usize> From<D128> for Amount<DECIMALS> {
-    fn from(value: D128) -> Self {
+impl<const DECIMALS: usize, const N: usize> From<Decimal<N>> for Amount<DECIMALS, N> {
+    fn from(value: Decimal<N>) -> Self {
         Self(value)
     }
 }
 
-impl<const DECIMALS: usize> From<Amount<DECIMALS>> for i32 {
+impl<const DECIMALS: usize, const N: usize> From<Amount<DECIMALS, N>> for i32 {
     /// # Panics
     ///
     /// May panic if the underlying number is outside i32 bounds. This should be avoided
     /// but is there to ensure backwards-compatibility.
-    fn from(value: Amount<DECIMALS>) -> Self {
+    fn from(value: Amount<DECIMALS, N>) -> Self {
         (value.0 * 10_i32.pow(DECIMALS as u32)).to_i32().unwrap()
     }
 }
 
-impl<const DECIMALS: usize> From<Amount<DECIMALS>> for i64 {
+impl<const DECIMALS: usize, const N: usize> From<Amount<DECIMALS, N>> for i64 {
     /// # Panics
     ///
     /// May panic if the underlying number is outside i64 bounds. This should be avoided
     /// but is there to ensure backwards-compatibility.
-    fn from(value: Amount<DECIMALS>) -> Self {
+    fn from(value: Amount<DECIMALS, N>) -> Self {
         (value.0 * 10_i64.pow(DECIMALS as u32)).to_i64().unwrap()
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Neg for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Neg for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     fn neg(self) -> Self::Output {
         Self(-self.0)
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Add for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Add for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     fn add(self, rhs: Self) -> Self::Output {
         Self(self.0 + rhs.0)
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Sub for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Sub for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         Self(self.0 - rhs.0)
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Div for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Div for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     /// Divide two same-decimals amounts while keeping the same number of decimals
     fn div(self, rhs: Self) -> Self::Output {
@@ -96,8 +367,8 @@ impl<const DECIMALS: usize> std::ops::Div for Amount<DECIMALS> {
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Mul for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Mul for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     /// Multiply two same-decimals amounts while keeping the same number of decimals
     fn mul(self, rhs: Self) -> Self::Output {
@@ -105,40 +376,54 @@ impl<const DECIMALS: usize> std::ops::Mul for Amount<DECIMALS> {
     }
 }
 
-impl<const DECIMALS: usize> From<Amount<DECIMALS>> for f64 {
-    fn from(value: Amount<DECIMALS>) -> Self {
+impl<const DECIMALS: usize, const N: usize> From<Amount<DECIMALS, N>> for f64 {
+    fn from(value: Amount<DECIMALS, N>) -> Self {
         value.0.to_f64()
     }
 }
 
-impl<const DECIMALS: usize> From<f64> for Amount<DECIMALS> {
+impl<const DECIMALS: usize, const N: usize> From<f64> for Amount<DECIMALS, N> {
     fn from(value: f64) -> Self {
         Self::new_f64(value)
     }
 }
 
-impl<const DECIMALS: usize> std::ops::AddAssign for Amount<DECIMALS> {
+impl<const DECIMALS: usize, const N: usize> std::ops::AddAssign for Amount<DECIMALS, N> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
 }
 
-impl<const DECIMALS: usize> std::ops::SubAssign for Amount<DECIMALS> {
+impl<const DECIMALS: usize, const N: usize> std::ops::SubAssign for Amount<DECIMALS, N> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Mul<i32> for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::iter::Sum for Amount<DECIMALS, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |total, amount| total + amount)
+    }
+}
+
+impl<'a, const DECIMALS: usize, const N: usize> std::iter::Sum<&'a Amount<DECIMALS, N>>
+    for Amount<DECIMALS, N>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const DECIMALS: usize, const N: usize> std::ops::Mul<i32> for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     fn mul(self, rhs: i32) -> Self::Output {
         Self(self.0 * rhs)
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Div<i32> for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Div<i32> for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     fn div(self, rhs: i32) -> Self::Output {
         assert_ne!(rhs, 0, "Attempt to divide Amount by zero");
@@ -146,16 +431,16 @@ impl<const DECIMALS: usize> std::ops::Div<i32> for Amount<DECIMALS> {
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Mul<f64> for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Mul<f64> for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     fn mul(self, rhs: f64) -> Self::Output {
         Self(self.0 * rhs)
     }
 }
 
-impl<const DECIMALS: usize> std::ops::Div<f64> for Amount<DECIMALS> {
-    type Output = Amount<DECIMALS>;
+impl<const DECIMALS: usize, const N: usize> std::ops::Div<f64> for Amount<DECIMALS, N> {
+    type Output = Amount<DECIMALS, N>;
 
     fn div(self, rhs: f64) -> Self::Output {
         assert_ne!(rhs, 0_f64, "Attempt to divide Amount by zero");
@@ -163,12 +448,105 @@ impl<const DECIMALS: usize> std::ops::Div<f64> for Amount<DECIMALS> {
     }
 }
 
-impl<const DECIMALS: usize> std::fmt::Display for Amount<DECIMALS> {
+impl<const DECIMALS: usize, const N: usize> std::fmt::Display for Amount<DECIMALS, N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
+impl<const DECIMALS: usize, const N: usize> MonetaryAmount for Amount<DECIMALS, N> {
+    const ZERO: Self = Self::ZERO;
+
+    fn from_minor_units(minor_units: i64) -> Self {
+        Self::new_scaled_i64(minor_units)
+    }
+
+    fn to_minor_units(&self) -> Option<i64> {
+        Amount::to_minor_units(self)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Amount::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Amount::checked_sub(self, rhs)
+    }
+
+    fn checked_mul_int(self, factor: i64) -> Option<Self> {
+        Self::checked(Self::untrapped(self.0) * Self::untrapped(Decimal::<N>::from_i64(factor)))
+    }
+
+    fn mul_ratio(self, numerator: i64, denominator: i64, mode: RoundingMode) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let product = self.checked_mul_int(numerator)?;
+        Some(Self(product.0 / Decimal::<N>::from_i64(denominator)).round(mode))
+    }
+}
+
+/// Minor units of amounts generated without an explicit range, e.g. ±10 000 000.00 for cents.
+#[cfg(any(feature = "dummy", feature = "proptest"))]
+const ARBITRARY_MINOR_UNITS: std::ops::RangeInclusive<i64> = -1_000_000_000..=1_000_000_000;
+
+#[cfg(any(feature = "dummy", feature = "proptest"))]
+impl<const DECIMALS: usize, const N: usize> Amount<DECIMALS, N> {
+    /// `range` in minor units, so that generated amounts never hold more than `DECIMALS` decimals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a bound holds more than `DECIMALS` decimals or does not fit an [`i64`] of minor units.
+    fn minor_units_range(range: &std::ops::RangeInclusive<Self>) -> std::ops::RangeInclusive<i64> {
+        let minor_units = |amount: &Self| {
+            amount.to_minor_units().unwrap_or_else(|| {
+                panic!("Amount range bound {amount} must be a whole number of minor units")
+            })
+        };
+
+        minor_units(range.start())..=minor_units(range.end())
+    }
+}
+
+/// `Faker` gives amounts within ±1 000 000 000 minor units, and a range gives amounts within it.
+#[cfg(feature = "dummy")]
+impl<const DECIMALS: usize, const N: usize> fake::Dummy<fake::Faker> for Amount<DECIMALS, N> {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(_: &fake::Faker, rng: &mut R) -> Self {
+        Self::new_scaled_i64(rng.random_range(ARBITRARY_MINOR_UNITS))
+    }
+}
+
+#[cfg(feature = "dummy")]
+impl<const DECIMALS: usize, const N: usize> fake::Dummy<std::ops::RangeInclusive<Self>>
+    for Amount<DECIMALS, N>
+{
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(
+        range: &std::ops::RangeInclusive<Self>,
+        rng: &mut R,
+    ) -> Self {
+        Self::new_scaled_i64(rng.random_range(Self::minor_units_range(range)))
+    }
+}
+
+/// Amounts with at most `DECIMALS` decimals, within ±1 000 000 000 minor units or, with
+/// `any_with::<Amount<2>>(Some(min..=max))`, within the given range.
+#[cfg(feature = "proptest")]
+impl<const DECIMALS: usize, const N: usize> proptest::arbitrary::Arbitrary for Amount<DECIMALS, N> {
+    type Parameters = Option<std::ops::RangeInclusive<Self>>;
+    type Strategy = proptest::strategy::Map<std::ops::RangeInclusive<i64>, fn(i64) -> Self>;
+
+    fn arbitrary_with(range: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        range
+            .map_or(ARBITRARY_MINOR_UNITS, |range| {
+                Self::minor_units_range(&range)
+            })
+            .prop_map(Self::new_scaled_i64)
+    }
+}
+
 /// Semantic type to indicate the underlying value is in Euros and not [`Cents`].
 pub type Euros = Amount<0>;
 
@@ -181,9 +559,10 @@ pub type Cents = Amount<2>;
 
 #[cfg(test)]
 mod tests {
-    use super::Amount;
+    use super::{Amount, Amount256, Amount512, NarrowAmountError, ParseAmountError};
+    use crate::domain::value_type::rounding::RoundingMode;
     use fastnum::decimal::{Context, Sign};
-    use fastnum::{D128, u128};
+    use fastnum::{D128, dec128, u128};
     use rstest::rstest;
 
     const F64_THRESHOLD: f64 = 0.001_f64;
@@ -388,4 +767,243 @@ mod tests {
 
         assert_eq!(amount, expected);
     }
+
+    #[rstest]
+    #[case("12.34", Amount::new_scaled_i32(1234), "12.34")]
+    #[case("12.3", Amount::new_scaled_i32(1230), "12.30")]
+    #[case(" -0.05 ", Amount::new_scaled_i32(-5), "-0.05")]
+    #[case("1000", Amount::new_scaled_i32(100000), "1000.00")]
+    #[case("12.340", Amount::new_scaled_i32(1234), "12.34")]
+    #[case("+7.000000", Amount::new_scaled_i32(700), "7.00")]
+    #[case("-0", Amount::new_f64(-0_f64), "-0.00")]
+    fn parse_amount2(#[case] input: &str, #[case] expected: Amount<2>, #[case] formatted: &str) {
+        let amount: Amount<2> = input.parse().unwrap();
+
+        assert_eq!(amount, expected);
+        assert_eq!(amount.to_string(), formatted);
+    }
+
+    #[rstest]
+    #[case("12.345", ParseAmountError::Scale { input: "12.345".to_string(), found: 3, expected: 2 })]
+    #[case("12,34", ParseAmountError::Invalid("12,34".to_string()))]
+    #[case("NaN", ParseAmountError::Invalid("NaN".to_string()))]
+    #[case("1e3", ParseAmountError::Invalid("1e3".to_string()))]
+    #[case("1E+100", ParseAmountError::Invalid("1E+100".to_string()))]
+    #[case("9e6144", ParseAmountError::Invalid("9e6144".to_string()))]
+    #[case("12.", ParseAmountError::Invalid("12.".to_string()))]
+    #[case(".5", ParseAmountError::Invalid(".5".to_string()))]
+    #[case("--1", ParseAmountError::Invalid("--1".to_string()))]
+    #[case("1 000", ParseAmountError::Invalid("1 000".to_string()))]
+    #[case("12.3050", ParseAmountError::Scale { input: "12.3050".to_string(), found: 3, expected: 2 })]
+    fn parse_amount2_rejects(#[case] input: &str, #[case] expected: ParseAmountError) {
+        assert_eq!(input.parse::<Amount<2>>(), Err(expected));
+    }
+
+    #[test]
+    fn parse_amount2_rejects_more_digits_than_fit() {
+        let too_large = format!("-1{}", "0".repeat(37));
+
+        assert_eq!(
+            too_large.parse::<Amount<2>>(),
+            Err(ParseAmountError::Invalid(too_large.clone()))
+        );
+        assert_eq!("9".repeat(36).parse::<Amount<2>>().unwrap().decimals(), 2);
+    }
+
+    #[rstest]
+    #[case(Amount::from(dec128!(12.345)), RoundingMode::HalfUp, "12.35")]
+    #[case(Amount::from(dec128!(12.345)), RoundingMode::HalfEven, "12.34")]
+    #[case(Amount::from(dec128!(-12.341)), RoundingMode::Floor, "-12.35")]
+    #[case(Amount::new_scaled_i32(1200), RoundingMode::Down, "12.00")]
+    fn round_amount2(#[case] input: Amount<2>, #[case] mode: RoundingMode, #[case] expected: &str) {
+        assert_eq!(input.round(mode).to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("12.32", "0.05", RoundingMode::HalfUp, "12.30")]
+    #[case("12.33", "0.05", RoundingMode::HalfUp, "12.35")]
+    #[case("12.375", "0.05", RoundingMode::HalfUp, "12.40")]
+    #[case("12.375", "0.05", RoundingMode::HalfDown, "12.35")]
+    #[case("-12.33", "0.05", RoundingMode::HalfUp, "-12.35")]
+    #[case("12.50", "1", RoundingMode::HalfUp, "13.00")]
+    #[case("12.50", "1", RoundingMode::HalfEven, "12.00")]
+    #[case("12.01", "0.10", RoundingMode::Ceiling, "12.10")]
+    #[case("12.30", "-0.05", RoundingMode::HalfUp, "12.30")]
+    fn round_amount3_to_increment(
+        #[case] input: &str,
+        #[case] increment: &str,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let amount: Amount<3> = input.parse().unwrap();
+        let rounded = amount.round_to_increment(increment.parse().unwrap(), mode);
+
+        assert_eq!(rounded, expected.parse().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempt to round Amount to a zero increment")]
+    fn round_amount2_to_zero_increment() {
+        Amount::<2>::new_scaled_i32(1234).round_to_increment(Amount::ZERO, RoundingMode::HalfUp);
+    }
+
+    #[test]
+    fn sum_amount2() {
+        let amounts = [
+            Amount::<2>::new_scaled_i32(1234),
+            Amount::new_scaled_i32(-34),
+            Amount::new_scaled_i32(100),
+        ];
+
+        assert_eq!(
+            amounts.iter().sum::<Amount<2>>(),
+            Amount::new_scaled_i32(1300)
+        );
+        assert_eq!(
+            amounts.into_iter().sum::<Amount<2>>(),
+            Amount::new_scaled_i32(1300)
+        );
+        assert_eq!(
+            std::iter::empty::<Amount<2>>().sum::<Amount<2>>(),
+            Amount::ZERO
+        );
+    }
+
+    #[test]
+    fn checked_add_amount2() {
+        let max = Amount::<2>::from(D128::MAX);
+
+        assert_eq!(
+            Amount::<2>::new_scaled_i32(1234).checked_add(Amount::new_scaled_i32(66)),
+            Some(Amount::new_scaled_i32(1300))
+        );
+        assert_eq!(max.checked_add(max), None);
+        assert_eq!((-max).checked_sub(max), None);
+        assert_eq!(
+            Amount::<2>::from(dec128!(1e37)).checked_add(Amount::new_scaled_i32(1)),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case("12.34", Some(1234))]
+    #[case("-0.05", Some(-5))]
+    #[case("92233720368547758.07", Some(i64::MAX))]
+    #[case("92233720368547758.08", None)]
+    fn amount2_to_minor_units(#[case] input: &str, #[case] expected: Option<i64>) {
+        let amount: Amount<2> = input.parse().unwrap();
+
+        assert_eq!(amount.to_minor_units(), expected);
+    }
+
+    #[test]
+    fn amount2_with_extra_decimals_to_minor_units() {
+        assert_eq!(Amount::<2>::new_f64(0.1).to_minor_units(), None);
+    }
+
+    #[test]
+    fn serde_amount2() {
+        let amount: Amount<2> = serde_json::from_str("\"12.3\"").unwrap();
+        assert_eq!(serde_json::to_string(&amount).unwrap(), "\"12.30\"");
+
+        let amount: Amount<2> = serde_json::from_str("12").unwrap();
+        assert_eq!(amount, Amount::new_scaled_i32(1200));
+
+        assert!(serde_json::from_str::<Amount<2>>("12.3").is_err());
+    }
+
+    #[test]
+    fn amount256_wei() {
+        let balance: Amount256<18> = "123456789012345678901234567890.123456789012345678"
+            .parse()
+            .unwrap();
+        let fee: Amount256<18> = "0.000000000000000001".parse().unwrap();
+
+        assert_eq!(
+            (balance - fee).to_string(),
+            "123456789012345678901234567890.123456789012345677"
+        );
+        assert_eq!(
+            balance.checked_add(balance).unwrap().to_string(),
+            "246913578024691357802469135780.246913578024691356"
+        );
+        assert_eq!(
+            Amount::<18>::try_from(balance),
+            Err(NarrowAmountError {
+                value: "123456789012345678901234567890.123456789012345678".to_string(),
+                bits: 128,
+            })
+        );
+        assert_eq!(balance.checked_resize::<2>(), None);
+        assert_eq!(
+            Amount512::from(balance).to_string(),
+            "123456789012345678901234567890.123456789012345678"
+        );
+    }
+
+    #[rstest]
+    #[case("12.34")]
+    #[case("-0.01")]
+    #[case("99999999999999999999999999999999999.99")]
+    fn resize_round_trip(#[case] input: &str) {
+        let amount: Amount<2> = input.parse().unwrap();
+        let wide = Amount256::from(amount);
+
+        assert_eq!(wide.to_string(), input);
+        assert_eq!(Amount512::from(wide).to_string(), input);
+        assert_eq!(Amount::<2>::try_from(wide), Ok(amount));
+        assert_eq!(
+            Amount::<2>::try_from(Amount512::from(amount))
+                .unwrap()
+                .to_string(),
+            input
+        );
+    }
+
+    #[cfg(feature = "dummy")]
+    #[test]
+    fn dummy_amount2() {
+        use fake::{Fake, Faker};
+
+        for _ in 0..1_000 {
+            let amount: Amount<2> = Faker.fake();
+            assert!(amount.decimals() <= 2, "{amount}");
+
+            let (min, max) = (Amount::new_scaled_i32(-50), Amount::new_scaled_i32(50));
+            let amount: Amount<2> = (min..=max).fake();
+            assert!((min..=max).contains(&amount), "{amount}");
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    mod properties {
+        use super::Amount;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn add_is_associative(a: Amount<2>, b: Amount<2>, c: Amount<2>) {
+                prop_assert_eq!((a + b) + c, a + (b + c));
+            }
+
+            #[test]
+            fn sub_self_is_zero(a: Amount<4>) {
+                prop_assert_eq!(a - a, Amount::ZERO);
+            }
+
+            #[test]
+            fn i64_round_trip(minor_units: i64) {
+                prop_assert_eq!(i64::from(Amount::<2>::new_scaled_i64(minor_units)), minor_units);
+                prop_assert_eq!(i64::from(Amount::<0>::new_scaled_i64(minor_units)), minor_units);
+            }
+
+            #[test]
+            fn arbitrary_respects_scale_and_range(
+                a in any_with::<Amount<2>>(Some(Amount::new_scaled_i32(-100)..=Amount::new_scaled_i32(100)))
+            ) {
+                prop_assert!(a.decimals() <= 2);
+                prop_assert!((Amount::new_scaled_i32(-100)..=Amount::new_scaled_i32(100)).contains(&a));
+            }
+        }
+    }
 }
@@ -0,0 +1,89 @@
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// An ISO 4217 alphabetic currency code, e.g. `EUR`.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialOrd, PartialEq, Hash)]
+pub struct Currency([u8; 3]);
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("invalid currency code '{0}', expected 3 uppercase ASCII letters")]
+pub struct ParseCurrencyError(String);
+
+impl Currency {
+    pub const AUD: Self = Self(*b"AUD");
+    pub const CAD: Self = Self(*b"CAD");
+    pub const CHF: Self = Self(*b"CHF");
+    pub const DKK: Self = Self(*b"DKK");
+    pub const EUR: Self = Self(*b"EUR");
+    pub const GBP: Self = Self(*b"GBP");
+    pub const JPY: Self = Self(*b"JPY");
+    pub const NOK: Self = Self(*b"NOK");
+    pub const NZD: Self = Self(*b"NZD");
+    pub const SEK: Self = Self(*b"SEK");
+    pub const USD: Self = Self(*b"USD");
+
+    pub fn code(&self) -> &str {
+        // Only ever built from uppercase ASCII letters.
+        std::str::from_utf8(&self.0).expect("currency code is ASCII")
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code: [u8; 3] = s
+            .as_bytes()
+            .try_into()
+            .map_err(|_| ParseCurrencyError(s.to_string()))?;
+
+        if !code.iter().all(u8::is_ascii_uppercase) {
+            return Err(ParseCurrencyError(s.to_string()));
+        }
+
+        Ok(Self(code))
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl serde::Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Currency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Currency, ParseCurrencyError};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("CHF", Ok(Currency::CHF))]
+    #[case("chf", Err(ParseCurrencyError("chf".to_string())))]
+    #[case("EURO", Err(ParseCurrencyError("EURO".to_string())))]
+    #[case("", Err(ParseCurrencyError("".to_string())))]
+    fn parse_currency(#[case] input: &str, #[case] expected: Result<Currency, ParseCurrencyError>) {
+        assert_eq!(input.parse::<Currency>(), expected);
+    }
+
+    #[test]
+    fn serde_currency() {
+        let currency: Currency = serde_json::from_str("\"SEK\"").unwrap();
+
+        assert_eq!(currency, Currency::SEK);
+        assert_eq!(serde_json::to_string(&currency).unwrap(), "\"SEK\"");
+    }
+}
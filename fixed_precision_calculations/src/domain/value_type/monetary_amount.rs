@@ -0,0 +1,47 @@
+use crate::domain::value_type::rounding::RoundingMode;
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
+/// Operations shared by the amount representations, so that hot paths can be written once and the
+/// backend picked by a type parameter, e.g. `fn invoice_total<A: MonetaryAmount>(lines: &[(A, i64)])`
+/// with [`Amount`](super::amount::Amount) ([`fastnum::D128`]) or
+/// [`ScaledAmount`](super::scaled_amount::ScaledAmount) (scaled [`i128`]).
+///
+/// The arithmetic operators panic on overflow; the `checked_*` methods return `None` instead. Both
+/// backends parse the same strings, see [`Amount::from_str`](super::amount::Amount#method.from_str).
+/// Only the operations here are guaranteed for every backend; the others, e.g. `Amount::round` or
+/// `Amount::new_f64`, are specific to one.
+pub trait MonetaryAmount:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Sum
+    + Send
+    + Sync
+{
+    const ZERO: Self;
+
+    /// Treats the input as a scaled integer (e.g. 1234 → 12.34 with 2 decimals).
+    fn from_minor_units(minor_units: i64) -> Self;
+
+    /// `None` if the amount holds more decimals than declared or does not fit an [`i64`].
+    fn to_minor_units(&self) -> Option<i64>;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies by a whole number, e.g. a unit price by a quantity.
+    fn checked_mul_int(self, factor: i64) -> Option<Self>;
+
+    /// Multiplies by `numerator / denominator`, rounded to the declared decimals with `mode`, e.g. a
+    /// net amount by 19 / 100 for VAT. `None` on overflow or a zero `denominator`.
+    fn mul_ratio(self, numerator: i64, denominator: i64, mode: RoundingMode) -> Option<Self>;
+}
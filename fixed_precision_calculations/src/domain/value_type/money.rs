@@ -0,0 +1,146 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use serde::{Deserialize, Serialize};
+use std::fmt::Formatter;
+
+/// A currency-aware monetary amount, e.g. 12.34 CHF.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Money<const DECIMALS: usize> {
+    amount: Amount<DECIMALS>,
+    currency: Currency,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("currency mismatch: {0} and {1}")]
+pub struct CurrencyMismatch(pub Currency, pub Currency);
+
+/// Why [`Money::checked_add`] or [`Money::checked_sub`] failed.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum MoneyError {
+    #[error(transparent)]
+    CurrencyMismatch(#[from] CurrencyMismatch),
+    #[error("{0} amount overflowed")]
+    Overflow(Currency),
+}
+
+impl<const DECIMALS: usize> Money<DECIMALS> {
+    pub const fn new(amount: Amount<DECIMALS>, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub const fn zero(currency: Currency) -> Self {
+        Self::new(Amount::ZERO, currency)
+    }
+
+    pub const fn amount(&self) -> Amount<DECIMALS> {
+        self.amount
+    }
+
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Adds `rhs`, failing if the currencies differ or the amount overflows, see
+    /// [`Amount::checked_add`].
+    pub fn checked_add(self, rhs: Self) -> Result<Self, MoneyError> {
+        self.ensure_same_currency(&rhs)?;
+
+        self.with_amount(self.amount.checked_add(rhs.amount))
+    }
+
+    /// Subtracts `rhs`, failing if the currencies differ or the amount overflows, see
+    /// [`Amount::checked_sub`].
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, MoneyError> {
+        self.ensure_same_currency(&rhs)?;
+
+        self.with_amount(self.amount.checked_sub(rhs.amount))
+    }
+
+    fn with_amount(&self, amount: Option<Amount<DECIMALS>>) -> Result<Self, MoneyError> {
+        amount
+            .map(|amount| Self::new(amount, self.currency))
+            .ok_or(MoneyError::Overflow(self.currency))
+    }
+
+    fn ensure_same_currency(&self, rhs: &Self) -> Result<(), CurrencyMismatch> {
+        if self.currency != rhs.currency {
+            return Err(CurrencyMismatch(self.currency, rhs.currency));
+        }
+
+        Ok(())
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::Neg for Money<DECIMALS> {
+    type Output = Money<DECIMALS>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.amount, self.currency)
+    }
+}
+
+impl<const DECIMALS: usize> std::fmt::Display for Money<DECIMALS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurrencyMismatch, Money, MoneyError};
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use fastnum::D128;
+
+    #[test]
+    fn add_same_currency() {
+        let lhs = Money::<2>::new(Amount::new_scaled_i32(1234), Currency::CHF);
+        let rhs = Money::<2>::new(Amount::new_scaled_i32(-34), Currency::CHF);
+
+        assert_eq!(lhs.checked_add(rhs).unwrap().to_string(), "12.00 CHF");
+        assert_eq!(lhs.checked_sub(rhs).unwrap().to_string(), "12.68 CHF");
+    }
+
+    #[test]
+    fn add_currency_mismatch() {
+        let lhs = Money::<2>::new(Amount::new_scaled_i32(1234), Currency::CHF);
+        let rhs = Money::<2>::new(Amount::new_scaled_i32(1234), Currency::EUR);
+
+        assert_eq!(
+            lhs.checked_add(rhs),
+            Err(MoneyError::CurrencyMismatch(CurrencyMismatch(
+                Currency::CHF,
+                Currency::EUR
+            )))
+        );
+    }
+
+    #[test]
+    fn add_overflow() {
+        let max = Money::<2>::new(Amount::from(D128::MAX), Currency::CHF);
+
+        assert_eq!(
+            max.checked_add(max),
+            Err(MoneyError::Overflow(Currency::CHF))
+        );
+        assert_eq!(
+            (-max).checked_sub(max),
+            Err(MoneyError::Overflow(Currency::CHF))
+        );
+    }
+
+    #[test]
+    fn serde_money() {
+        let money: Money<2> =
+            serde_json::from_str(r#"{ "amount": "12.3", "currency": "EUR" }"#).unwrap();
+
+        assert_eq!(
+            money,
+            Money::new(Amount::new_scaled_i32(1230), Currency::EUR)
+        );
+        assert_eq!(
+            serde_json::to_string(&money).unwrap(),
+            r#"{"amount":"12.30","currency":"EUR"}"#
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::scaled_amount::{MinorUnitsError, ScaledAmount};
+use std::fmt::Formatter;
+
+/// An amount packed into 8 bytes as [`i64`] minor units, e.g. 1234 for 12.34 with 2 decimals.
+///
+/// Meant for storing many amounts, e.g. in an [`AmountVec`](crate::domain::amount_vec::AmountVec),
+/// rather than for arithmetic: convert to [`Amount`] or [`ScaledAmount`] to calculate. Conversions
+/// from those fail instead of losing precision when a value holds more than `DECIMALS` decimals or
+/// does not fit.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialOrd, PartialEq, Hash, Default)]
+pub struct PackedAmount<const DECIMALS: usize>(i64);
+
+impl<const DECIMALS: usize> PackedAmount<DECIMALS> {
+    pub const ZERO: Self = Self(0);
+    pub const MAX: Self = Self(i64::MAX);
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// Treats the input as a scaled integer (e.g. 1234 → 12.34)
+    pub const fn new_scaled_i64(inner: i64) -> Self {
+        Self(inner)
+    }
+
+    pub const fn minor_units(&self) -> i64 {
+        self.0
+    }
+}
+
+impl<const DECIMALS: usize> TryFrom<Amount<DECIMALS>> for PackedAmount<DECIMALS> {
+    type Error = MinorUnitsError;
+
+    fn try_from(value: Amount<DECIMALS>) -> Result<Self, Self::Error> {
+        ScaledAmount::try_from(value).and_then(Self::try_from)
+    }
+}
+
+impl<const DECIMALS: usize> TryFrom<ScaledAmount<DECIMALS>> for PackedAmount<DECIMALS> {
+    type Error = MinorUnitsError;
+
+    fn try_from(value: ScaledAmount<DECIMALS>) -> Result<Self, Self::Error> {
+        value
+            .to_minor_units()
+            .map(Self)
+            .ok_or_else(|| MinorUnitsError::Overflow(value.to_string()))
+    }
+}
+
+impl<const DECIMALS: usize> From<PackedAmount<DECIMALS>> for ScaledAmount<DECIMALS> {
+    fn from(value: PackedAmount<DECIMALS>) -> Self {
+        Self::new_scaled_i64(value.0)
+    }
+}
+
+impl<const DECIMALS: usize> From<PackedAmount<DECIMALS>> for Amount<DECIMALS> {
+    fn from(value: PackedAmount<DECIMALS>) -> Self {
+        ScaledAmount::from(value).into()
+    }
+}
+
+impl<const DECIMALS: usize> std::fmt::Display for PackedAmount<DECIMALS> {
+    /// Always shows `DECIMALS` decimals, e.g. "12.30".
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&ScaledAmount::<DECIMALS>::from(*self), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackedAmount;
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::scaled_amount::{MinorUnitsError, ScaledAmount};
+    use rstest::rstest;
+
+    #[test]
+    fn eight_bytes() {
+        assert_eq!(size_of::<PackedAmount<2>>(), 8);
+        assert!(size_of::<Amount<2>>() > size_of::<PackedAmount<2>>());
+    }
+
+    #[rstest]
+    #[case("12.34", 1234)]
+    #[case("-0.05", -5)]
+    #[case("92233720368547758.07", i64::MAX)]
+    #[case("-92233720368547758.08", i64::MIN)]
+    fn lossless_round_trip(#[case] input: &str, #[case] minor_units: i64) {
+        let amount: Amount<2> = input.parse().unwrap();
+        let packed = PackedAmount::try_from(amount).unwrap();
+
+        assert_eq!(packed.minor_units(), minor_units);
+        assert_eq!(Amount::from(packed), amount);
+        assert_eq!(packed.to_string(), input);
+    }
+
+    #[test]
+    fn rejects_what_does_not_fit() {
+        let too_large: Amount<2> = "92233720368547758.08".parse().unwrap();
+        assert_eq!(
+            PackedAmount::try_from(too_large),
+            Err(MinorUnitsError::Overflow(
+                "92233720368547758.08".to_string()
+            ))
+        );
+
+        assert!(matches!(
+            PackedAmount::try_from(Amount::<2>::new_f64(0.1)),
+            Err(MinorUnitsError::Scale { expected: 2, .. })
+        ));
+
+        assert!(PackedAmount::try_from(ScaledAmount::<2>::MAX).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::domain::value_type::amount::Amount;
+use fastnum::D128;
+use fastnum::decimal::Context;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+const HUNDRED: D128 = D128::from_i32(100);
+
+/// A percentage held as an exact decimal number of percentage points (e.g. 19.5 → 19.5%).
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialOrd, PartialEq, Hash, Default)]
+pub struct Percentage(D128);
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("invalid percentage '{0}'")]
+pub struct ParsePercentageError(String);
+
+impl Percentage {
+    pub const ZERO: Self = Self(D128::ZERO);
+
+    pub const fn new(points: D128) -> Self {
+        Self(points)
+    }
+
+    /// Builds a percentage from a ratio (e.g. 0.195 → 19.5%).
+    pub const fn from_ratio(ratio: D128) -> Self {
+        Self(ratio.mul(HUNDRED))
+    }
+
+    /// Number of percentage points (e.g. 19.5% → 19.5).
+    pub const fn points(&self) -> D128 {
+        self.0
+    }
+
+    /// The percentage as a ratio (e.g. 19.5% → 0.195).
+    pub const fn ratio(&self) -> D128 {
+        self.0.div(HUNDRED)
+    }
+
+    /// Applies the percentage to an amount without any rounding, so the result may carry more than
+    /// `DECIMALS` decimals.
+    pub fn of<const DECIMALS: usize>(&self, amount: Amount<DECIMALS>) -> Amount<DECIMALS> {
+        Amount::from(amount.raw() * self.ratio())
+    }
+
+    /// Rounds to `decimals` decimal places of percentage points, e.g. 33.3333…% → 33.33%.
+    pub fn round(self, decimals: i16) -> Self {
+        Self(self.0.round(decimals))
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = ParsePercentageError;
+
+    /// Parses "19.5" or "19.5%" as 19.5%.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points = s.trim().trim_end_matches('%').trim_end();
+
+        D128::from_str(points, Context::default())
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Self)
+            .ok_or_else(|| ParsePercentageError(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for Percentage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl serde::Serialize for Percentage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Percentage {
+    /// Accepts strings such as "19.5" or "19.5%", and integers (e.g. 20 → 20%).
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Percentage;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                write!(f, "a percentage string such as \"19.5%\", or an integer")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(Percentage(D128::from_i64(v)))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Percentage(D128::from_u64(v)))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Percentage;
+    use crate::domain::value_type::amount::Amount;
+    use fastnum::dec128;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("19.5", Percentage::new(dec128!(19.5)))]
+    #[case("19.5%", Percentage::new(dec128!(19.5)))]
+    #[case(" 0 % ", Percentage::ZERO)]
+    fn parse_percentage(#[case] input: &str, #[case] expected: Percentage) {
+        assert_eq!(input.parse::<Percentage>().unwrap(), expected);
+    }
+
+    #[test]
+    fn ratio_round_trip() {
+        let percentage = Percentage::from_ratio(dec128!(0.195));
+
+        assert_eq!(percentage.to_string(), "19.500%");
+        assert_eq!(percentage.ratio(), dec128!(0.195));
+    }
+
+    #[test]
+    fn of_amount() {
+        let amount: Amount<2> = Amount::new_scaled_i32(12345);
+        let vat = Percentage::new(dec128!(20)).of(amount);
+
+        assert_eq!(vat, Amount::from(dec128!(24.69)));
+    }
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+/// How a value is rounded when it has to be brought down to a fixed number of decimals.
+///
+/// This mirrors [`fastnum::decimal::RoundingMode`] but can be (de)serialised, so it can be set from
+/// configuration files (e.g. per jurisdiction).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round away from zero.
+    Up,
+    /// Round towards zero, i.e. truncate.
+    Down,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round to nearest, ties away from zero (e.g. 2.345 → 2.35).
+    #[default]
+    HalfUp,
+    /// Round to nearest, ties towards zero (e.g. 2.345 → 2.34).
+    HalfDown,
+    /// Round to nearest, ties to the even neighbour, a.k.a. banker's rounding (e.g. 2.345 → 2.34).
+    HalfEven,
+}
+
+impl From<RoundingMode> for fastnum::decimal::RoundingMode {
+    fn from(value: RoundingMode) -> Self {
+        match value {
+            RoundingMode::Up => Self::Up,
+            RoundingMode::Down => Self::Down,
+            RoundingMode::Ceiling => Self::Ceiling,
+            RoundingMode::Floor => Self::Floor,
+            RoundingMode::HalfUp => Self::HalfUp,
+            RoundingMode::HalfDown => Self::HalfDown,
+            RoundingMode::HalfEven => Self::HalfEven,
+        }
+    }
+}
+
+impl RoundingMode {
+    /// Divides two integers, rounding the quotient with this mode, e.g. 25 / 10 → 3 with
+    /// [`RoundingMode::HalfUp`]. `None` when `denominator` is zero or the quotient overflows.
+    pub fn div_i128(self, numerator: i128, denominator: i128) -> Option<i128> {
+        if denominator == 0 || (numerator == i128::MIN && denominator == -1) {
+            return None;
+        }
+
+        let quotient = numerator / denominator;
+        let remainder = (numerator % denominator).unsigned_abs();
+        if remainder == 0 {
+            return Some(quotient);
+        }
+
+        let negative = (numerator < 0) != (denominator < 0);
+        let away = if negative { quotient - 1 } else { quotient + 1 };
+        // Compared without doubling the remainder, which could overflow.
+        let beyond_half = remainder.cmp(&(denominator.unsigned_abs() - remainder));
+
+        let round_away = match self {
+            Self::Up => true,
+            Self::Down => false,
+            Self::Ceiling => !negative,
+            Self::Floor => negative,
+            Self::HalfUp => !beyond_half.is_lt(),
+            Self::HalfDown => beyond_half.is_gt(),
+            Self::HalfEven => beyond_half.is_gt() || (beyond_half.is_eq() && quotient % 2 != 0),
+        };
+
+        Some(if round_away { away } else { quotient })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoundingMode;
+    use fastnum::{D128, dec128};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(RoundingMode::Up, dec128!(2.341), "2.35")]
+    #[case(RoundingMode::Down, dec128!(2.349), "2.34")]
+    #[case(RoundingMode::Ceiling, dec128!(-2.349), "-2.34")]
+    #[case(RoundingMode::Floor, dec128!(-2.341), "-2.35")]
+    #[case(RoundingMode::HalfUp, dec128!(2.345), "2.35")]
+    #[case(RoundingMode::HalfDown, dec128!(2.345), "2.34")]
+    #[case(RoundingMode::HalfEven, dec128!(2.345), "2.34")]
+    #[case(RoundingMode::HalfEven, dec128!(2.355), "2.36")]
+    fn maps_to_fastnum(#[case] mode: RoundingMode, #[case] input: D128, #[case] expected: &str) {
+        let rounded = input.with_rounding_mode(mode.into()).round(2);
+
+        assert_eq!(rounded.to_string(), expected);
+    }
+
+    #[test]
+    fn deserialise_snake_case() {
+        let mode: RoundingMode = serde_json::from_str("\"half_even\"").unwrap();
+
+        assert_eq!(mode, RoundingMode::HalfEven);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::Up, [3, -3, 3, -2])]
+    #[case(RoundingMode::Down, [2, -2, 2, -2])]
+    #[case(RoundingMode::Ceiling, [3, -2, 3, -2])]
+    #[case(RoundingMode::Floor, [2, -3, 2, -2])]
+    #[case(RoundingMode::HalfUp, [3, -3, 3, -2])]
+    #[case(RoundingMode::HalfDown, [2, -2, 3, -2])]
+    #[case(RoundingMode::HalfEven, [2, -2, 3, -2])]
+    fn div_i128(#[case] mode: RoundingMode, #[case] expected: [i128; 4]) {
+        // 2.5, -2.5, 2.6, -2
+        let quotients = [(25, 10), (25, -10), (26, 10), (-20, 10)]
+            .map(|(numerator, denominator)| mode.div_i128(numerator, denominator).unwrap());
+
+        assert_eq!(quotients, expected);
+    }
+
+    #[test]
+    fn div_i128_edge_cases() {
+        assert_eq!(RoundingMode::HalfUp.div_i128(1, 0), None);
+        assert_eq!(RoundingMode::HalfUp.div_i128(i128::MIN, -1), None);
+        assert_eq!(
+            RoundingMode::HalfEven.div_i128(i128::MAX, i128::MAX - 1),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,550 @@
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+use crate::domain::value_type::monetary_amount::MonetaryAmount;
+use crate::domain::value_type::rounding::RoundingMode;
+use fastnum::D128;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// An amount stored as an [`i128`] number of minor units, e.g. 1234 for 12.34 with 2 decimals.
+///
+/// This is an alternative to the [`D128`]-backed [`Amount`] for hot paths: construction is a plain
+/// integer and arithmetic never rounds, but every value always has exactly `DECIMALS` decimals, so
+/// operations that would create more (e.g. applying a tax rate) take a [`RoundingMode`]. Overflow is
+/// detected: operators panic and `checked_*` methods return `None`.
+///
+/// Write code generic over [`MonetaryAmount`] to switch between the two. Operations that rely on
+/// decimal arithmetic, such as [`Amount::round`] or [`Amount::new_f64`], are not offered; convert
+/// with [`Amount::from`] for those.
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialOrd, PartialEq, Hash, Default)]
+pub struct ScaledAmount<const DECIMALS: usize>(i128);
+
+/// An [`Amount`] that cannot be held as an integer number of minor units.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum MinorUnitsError {
+    #[error("{value} has more than {expected} decimals")]
+    Scale { value: String, expected: usize },
+    #[error("{0} does not fit in minor units")]
+    Overflow(String),
+}
+
+impl<const DECIMALS: usize> ScaledAmount<DECIMALS> {
+    pub const ZERO: Self = Self(0);
+    pub const MAX: Self = Self(i128::MAX);
+    pub const MIN: Self = Self(i128::MIN);
+
+    /// Minor units in one whole unit, e.g. 100 for 2 decimals.
+    const UNIT: i128 = 10_i128.pow(DECIMALS as u32);
+
+    /// Treats the input as a scaled integer (e.g. 1234 → 12.34)
+    pub const fn new_scaled_i32(inner: i32) -> Self {
+        Self(inner as i128)
+    }
+
+    /// Treats the input as a scaled integer (e.g. 1234 → 12.34)
+    pub const fn new_scaled_i64(inner: i64) -> Self {
+        Self(inner as i128)
+    }
+
+    /// Treats the input as a scaled integer (e.g. 1234 → 12.34)
+    pub const fn new_scaled_i128(inner: i128) -> Self {
+        Self(inner)
+    }
+
+    pub const fn minor_units(&self) -> i128 {
+        self.0
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Number of decimals held, which is always `DECIMALS`.
+    pub const fn decimals(&self) -> i16 {
+        DECIMALS as i16
+    }
+
+    /// # Panics
+    ///
+    /// Panics on [`ScaledAmount::MIN`], whose absolute value does not fit.
+    pub const fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// The amount as an [`i64`] number of minor units, `None` if it does not fit.
+    pub const fn to_minor_units(&self) -> Option<i64> {
+        if self.0 < i64::MIN as i128 || self.0 > i64::MAX as i128 {
+            return None;
+        }
+
+        Some(self.0 as i64)
+    }
+
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_mul(self, factor: i64) -> Option<Self> {
+        match self.0.checked_mul(factor as i128) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Multiplies by `numerator / denominator`, rounded with `mode` (e.g. 10.01 × 1/2 → 5.01 with
+    /// [`RoundingMode::HalfUp`]). `None` on overflow or a zero `denominator`.
+    pub fn mul_ratio(self, numerator: i64, denominator: i64, mode: RoundingMode) -> Option<Self> {
+        let scaled = self.0.checked_mul(numerator as i128)?;
+
+        mode.div_i128(scaled, denominator as i128).map(Self)
+    }
+
+    /// Divides by a whole number, rounded with `mode`. `None` on overflow or a zero `divisor`.
+    pub fn checked_div(self, divisor: i64, mode: RoundingMode) -> Option<Self> {
+        mode.div_i128(self.0, divisor as i128).map(Self)
+    }
+
+    /// Rounds to a multiple of `increment` using the given rounding mode, see
+    /// [`Amount::round_to_increment`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `increment` is zero.
+    pub fn round_to_increment(self, increment: Self, mode: RoundingMode) -> Self {
+        assert!(
+            !increment.is_zero(),
+            "Attempt to round Amount to a zero increment"
+        );
+
+        let increment = increment.0.unsigned_abs() as i128;
+        let steps = mode
+            .div_i128(self.0, increment)
+            .expect("increment is not zero");
+
+        Self(
+            steps
+                .checked_mul(increment)
+                .expect("Amount overflowed rounding to increment"),
+        )
+    }
+}
+
+impl<const DECIMALS: usize> FromStr for ScaledAmount<DECIMALS> {
+    type Err = ParseAmountError;
+
+    /// Parses the same strings as [`Amount::from_str`] (e.g. "-12.3" → -12.30), straight into minor
+    /// units.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseAmountError::Invalid(s.to_string());
+        let input = s.trim();
+        let unsigned = input.strip_prefix(['+', '-']).unwrap_or(input);
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, "0"));
+
+        let is_digits =
+            |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+        if !is_digits(whole) || !is_digits(fraction) {
+            return Err(invalid());
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > DECIMALS {
+            return Err(ParseAmountError::Scale {
+                input: s.to_string(),
+                found: fraction.len() as i16,
+                expected: DECIMALS,
+            });
+        }
+
+        let minor_units = whole
+            .bytes()
+            .chain(fraction.bytes())
+            .chain(std::iter::repeat_n(b'0', DECIMALS - fraction.len()))
+            .try_fold(0_i128, |total, digit| {
+                total.checked_mul(10)?.checked_add((digit - b'0') as i128)
+            })
+            .ok_or_else(invalid)?;
+
+        Ok(Self(if input.starts_with('-') {
+            -minor_units
+        } else {
+            minor_units
+        }))
+    }
+}
+
+impl<const DECIMALS: usize> std::fmt::Display for ScaledAmount<DECIMALS> {
+    /// Always shows `DECIMALS` decimals, e.g. "12.30".
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let magnitude = self.0.unsigned_abs();
+
+        if DECIMALS == 0 {
+            return write!(f, "{sign}{magnitude}");
+        }
+
+        let unit = Self::UNIT as u128;
+        write!(
+            f,
+            "{sign}{}.{:0width$}",
+            magnitude / unit,
+            magnitude % unit,
+            width = DECIMALS
+        )
+    }
+}
+
+impl<const DECIMALS: usize> serde::Serialize for ScaledAmount<DECIMALS> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, const DECIMALS: usize> serde::Deserialize<'de> for ScaledAmount<DECIMALS> {
+    /// Accepts the same inputs as [`Amount`]: decimal strings and integers (whole units).
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let amount = Amount::<DECIMALS>::deserialize(deserializer)?;
+
+        Self::try_from(amount).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<const DECIMALS: usize> From<ScaledAmount<DECIMALS>> for Amount<DECIMALS> {
+    fn from(value: ScaledAmount<DECIMALS>) -> Self {
+        let minor_units = D128::from_i128(value.0);
+
+        Amount::from(minor_units / D128::TEN.powi(DECIMALS as i32)).round(RoundingMode::HalfUp)
+    }
+}
+
+impl<const DECIMALS: usize> TryFrom<Amount<DECIMALS>> for ScaledAmount<DECIMALS> {
+    type Error = MinorUnitsError;
+
+    fn try_from(value: Amount<DECIMALS>) -> Result<Self, Self::Error> {
+        if value.decimals() > DECIMALS as i16 {
+            return Err(MinorUnitsError::Scale {
+                value: value.to_string(),
+                expected: DECIMALS,
+            });
+        }
+
+        value
+            .to_minor_units_i128()
+            .map(Self)
+            .ok_or_else(|| MinorUnitsError::Overflow(value.to_string()))
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::Neg for ScaledAmount<DECIMALS> {
+    type Output = ScaledAmount<DECIMALS>;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.checked_neg().expect("Amount overflowed negating"))
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::Add for ScaledAmount<DECIMALS> {
+    type Output = ScaledAmount<DECIMALS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("Amount overflowed adding")
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::Sub for ScaledAmount<DECIMALS> {
+    type Output = ScaledAmount<DECIMALS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("Amount overflowed subtracting")
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::Mul<i64> for ScaledAmount<DECIMALS> {
+    type Output = ScaledAmount<DECIMALS>;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("Amount overflowed multiplying")
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::AddAssign for ScaledAmount<DECIMALS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const DECIMALS: usize> std::ops::SubAssign for ScaledAmount<DECIMALS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const DECIMALS: usize> std::iter::Sum for ScaledAmount<DECIMALS> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |total, amount| total + amount)
+    }
+}
+
+impl<'a, const DECIMALS: usize> std::iter::Sum<&'a ScaledAmount<DECIMALS>>
+    for ScaledAmount<DECIMALS>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const DECIMALS: usize> MonetaryAmount for ScaledAmount<DECIMALS> {
+    const ZERO: Self = Self::ZERO;
+
+    fn from_minor_units(minor_units: i64) -> Self {
+        Self::new_scaled_i64(minor_units)
+    }
+
+    fn to_minor_units(&self) -> Option<i64> {
+        ScaledAmount::to_minor_units(self)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        ScaledAmount::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        ScaledAmount::checked_sub(self, rhs)
+    }
+
+    fn checked_mul_int(self, factor: i64) -> Option<Self> {
+        self.checked_mul(factor)
+    }
+
+    fn mul_ratio(self, numerator: i64, denominator: i64, mode: RoundingMode) -> Option<Self> {
+        ScaledAmount::mul_ratio(self, numerator, denominator, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MinorUnitsError, ScaledAmount};
+    use crate::domain::amount_vec::AmountVec;
+    use crate::domain::value_type::amount::{Amount, ParseAmountError};
+    use crate::domain::value_type::monetary_amount::MonetaryAmount;
+    use crate::domain::value_type::packed_amount::PackedAmount;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use fastnum::D128;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("12.34", Ok(ScaledAmount::<2>::new_scaled_i32(1234)))]
+    #[case("12.3", Ok(ScaledAmount::new_scaled_i32(1230)))]
+    #[case(" -0.05 ", Ok(ScaledAmount::new_scaled_i32(-5)))]
+    #[case("+7", Ok(ScaledAmount::new_scaled_i32(700)))]
+    #[case("12.340", Ok(ScaledAmount::new_scaled_i32(1234)))]
+    #[case(".5", Err(ParseAmountError::Invalid(".5".to_string())))]
+    #[case("12.", Err(ParseAmountError::Invalid("12.".to_string())))]
+    #[case("12.345", Err(ParseAmountError::Scale { input: "12.345".to_string(), found: 3, expected: 2 }))]
+    #[case("1e3", Err(ParseAmountError::Invalid("1e3".to_string())))]
+    #[case("-", Err(ParseAmountError::Invalid("-".to_string())))]
+    #[case("1.2.3", Err(ParseAmountError::Invalid("1.2.3".to_string())))]
+    #[case(
+        "9999999999999999999999999999999999999999",
+        Err(ParseAmountError::Invalid("9999999999999999999999999999999999999999".to_string()))
+    )]
+    fn parse_scaled_amount2(
+        #[case] input: &str,
+        #[case] expected: Result<ScaledAmount<2>, ParseAmountError>,
+    ) {
+        assert_eq!(input.parse::<ScaledAmount<2>>(), expected);
+    }
+
+    #[rstest]
+    #[case("12.34")]
+    #[case("12.340")]
+    #[case("+7.000000")]
+    #[case("-0")]
+    #[case(".5")]
+    #[case("12.")]
+    #[case("12.345")]
+    #[case("12.3050")]
+    #[case("1e3")]
+    #[case("--1")]
+    #[case("1 000")]
+    fn backends_parse_alike(#[case] input: &str) {
+        // Compared in minor units, as "-0" is a negative zero only for `Amount`.
+        assert_eq!(
+            input
+                .parse::<ScaledAmount<2>>()
+                .map(|amount| amount.to_minor_units()),
+            input
+                .parse::<Amount<2>>()
+                .map(|amount| amount.to_minor_units()),
+        );
+    }
+
+    #[rstest]
+    #[case(1234, "12.34")]
+    #[case(-5, "-0.05")]
+    #[case(0, "0.00")]
+    fn display_scaled_amount2(#[case] minor_units: i32, #[case] expected: &str) {
+        assert_eq!(
+            ScaledAmount::<2>::new_scaled_i32(minor_units).to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn display_scaled_amount0() {
+        assert_eq!(ScaledAmount::<0>::new_scaled_i32(-12).to_string(), "-12");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let price = ScaledAmount::<2>::new_scaled_i32(1999);
+
+        assert_eq!(price * 3, ScaledAmount::new_scaled_i32(5997));
+        assert_eq!(price + price - price, price);
+        assert_eq!(-price, ScaledAmount::new_scaled_i32(-1999));
+        assert_eq!([price, price].iter().sum::<ScaledAmount<2>>(), price * 2);
+    }
+
+    #[test]
+    fn overflow_is_detected() {
+        assert_eq!(
+            ScaledAmount::<2>::MAX.checked_add(ScaledAmount::new_scaled_i32(1)),
+            None
+        );
+        assert_eq!(
+            ScaledAmount::<2>::MIN.checked_sub(ScaledAmount::new_scaled_i32(1)),
+            None
+        );
+        assert_eq!(ScaledAmount::<2>::MAX.checked_mul(2), None);
+        assert_eq!(ScaledAmount::<2>::MAX.to_minor_units(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Amount overflowed adding")]
+    fn add_overflow_panics() {
+        let _ = ScaledAmount::<2>::MAX + ScaledAmount::new_scaled_i32(1);
+    }
+
+    #[rstest]
+    #[case(1001, 1, 2, RoundingMode::HalfUp, Some(501))]
+    #[case(1001, 1, 2, RoundingMode::HalfEven, Some(500))]
+    #[case(-1001, 1, 2, RoundingMode::HalfUp, Some(-501))]
+    #[case(10000, 19, 100, RoundingMode::HalfUp, Some(1900))]
+    #[case(1, 1, 0, RoundingMode::HalfUp, None)]
+    fn mul_ratio(
+        #[case] minor_units: i32,
+        #[case] numerator: i64,
+        #[case] denominator: i64,
+        #[case] mode: RoundingMode,
+        #[case] expected: Option<i32>,
+    ) {
+        assert_eq!(
+            ScaledAmount::<2>::new_scaled_i32(minor_units).mul_ratio(numerator, denominator, mode),
+            expected.map(ScaledAmount::new_scaled_i32)
+        );
+    }
+
+    #[rstest]
+    #[case(1234, RoundingMode::HalfUp, 1235)]
+    #[case(1232, RoundingMode::HalfUp, 1230)]
+    #[case(-1238, RoundingMode::Down, -1235)]
+    fn round_to_increment(
+        #[case] minor_units: i32,
+        #[case] mode: RoundingMode,
+        #[case] expected: i32,
+    ) {
+        assert_eq!(
+            ScaledAmount::<2>::new_scaled_i32(minor_units)
+                .round_to_increment(ScaledAmount::new_scaled_i32(5), mode),
+            ScaledAmount::new_scaled_i32(expected)
+        );
+    }
+
+    #[test]
+    fn amount_conversions() {
+        let amount: Amount<2> = "-12.30".parse().unwrap();
+        let scaled = ScaledAmount::<2>::try_from(amount).unwrap();
+
+        assert_eq!(scaled, ScaledAmount::new_scaled_i32(-1230));
+        assert_eq!(Amount::from(scaled), amount);
+        assert_eq!(Amount::from(scaled).to_string(), "-12.30");
+        assert_eq!(
+            ScaledAmount::<2>::try_from(Amount::<2>::new_f64(0.1)),
+            Err(MinorUnitsError::Scale {
+                value: Amount::<2>::new_f64(0.1).to_string(),
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn amount_overflow() {
+        let huge = Amount::<2>::from(D128::MAX);
+
+        assert_eq!(
+            ScaledAmount::<2>::try_from(huge),
+            Err(MinorUnitsError::Overflow(huge.to_string()))
+        );
+        assert_eq!(
+            PackedAmount::<2>::try_from(huge),
+            Err(MinorUnitsError::Overflow(huge.to_string()))
+        );
+        assert!(AmountVec::<2>::new().push(huge).is_err());
+        assert_eq!(huge.to_minor_units_i128(), None);
+    }
+
+    #[test]
+    fn serde_scaled_amount2() {
+        let amount: ScaledAmount<2> = serde_json::from_str("\"12.3\"").unwrap();
+
+        assert_eq!(amount, ScaledAmount::new_scaled_i32(1230));
+        assert_eq!(serde_json::to_string(&amount).unwrap(), "\"12.30\"");
+        assert_eq!(
+            serde_json::from_str::<ScaledAmount<2>>("12").unwrap(),
+            ScaledAmount::new_scaled_i32(1200)
+        );
+    }
+
+    /// Prices line items the way a hot path would, for either backend.
+    fn invoice_total<A: MonetaryAmount>(lines: &[(&str, i64)]) -> A
+    where
+        A::Err: std::fmt::Debug,
+    {
+        lines
+            .iter()
+            .map(|(price, quantity)| {
+                let net = price
+                    .parse::<A>()
+                    .unwrap()
+                    .checked_mul_int(*quantity)
+                    .unwrap();
+                net + net.mul_ratio(19, 100, RoundingMode::HalfEven).unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn backends_agree() {
+        let lines = [("19.99", 3), ("0.15", 7), ("1234.56", 1), ("-5.00", 2)];
+
+        let scaled: ScaledAmount<2> = invoice_total(&lines);
+        let decimal: Amount<2> = invoice_total(&lines);
+
+        assert_eq!(scaled.to_string(), "1529.84");
+        assert_eq!(Amount::from(scaled), decimal);
+    }
+}
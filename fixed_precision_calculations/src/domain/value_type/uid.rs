@@ -0,0 +1,180 @@
+use rand::Rng;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+};
+
+/// A UUID typed by the entity it identifies, so a `Uid<Transaction>` cannot be passed where a
+/// `Uid<Account>` is expected.
+pub struct Uid<T> {
+    inner: uuid::Uuid,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Uid<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Uid<T> {}
+
+impl<T> Uid<T> {
+    const fn new(inner: uuid::Uuid) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn new_v4() -> Self {
+        Self::new(uuid::Uuid::new_v4())
+    }
+
+    /// A version 4 UUID drawn from `rng`, so that seeded generators produce reproducible ids.
+    pub fn from_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(uuid::Builder::from_random_bytes(rng.random()).into_uuid())
+    }
+}
+
+impl<T> From<uuid::Uuid> for Uid<T> {
+    fn from(value: uuid::Uuid) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<Uid<T>> for uuid::Uuid {
+    fn from(value: Uid<T>) -> Self {
+        value.inner
+    }
+}
+
+impl<T> FromStr for Uid<T> {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self::new)
+    }
+}
+
+impl<T> Debug for Uid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Uid").field(&self.inner).finish()
+    }
+}
+
+impl<T> Display for Uid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl<T> PartialEq for Uid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T> Eq for Uid<T> {}
+
+impl<T> Hash for Uid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<T> serde::Serialize for Uid<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Uid<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        uuid::Uuid::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "dummy")]
+impl<T> fake::Dummy<fake::Faker> for Uid<T> {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &fake::Faker, rng: &mut R) -> Self {
+        Self::from_rng(rng)
+    }
+}
+
+/// Random version 4 UUIDs.
+#[cfg(feature = "proptest")]
+impl<T> proptest::arbitrary::Arbitrary for Uid<T> {
+    type Parameters = ();
+    type Strategy =
+        proptest::strategy::Map<proptest::arbitrary::StrategyFor<[u8; 16]>, fn([u8; 16]) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::arbitrary::any::<[u8; 16]>()
+            .prop_map(|bytes| Self::new(uuid::Builder::from_random_bytes(bytes).into_uuid()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Uid;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    struct Account;
+
+    #[test]
+    fn from_rng_is_reproducible_v4() {
+        let first: Uid<Account> = Uid::from_rng(&mut StdRng::seed_from_u64(1));
+        let second: Uid<Account> = Uid::from_rng(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(first, second);
+        assert_eq!(uuid::Uuid::from(first).get_version_num(), 4);
+    }
+
+    #[test]
+    fn serde_uid() {
+        let uid: Uid<Account> = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+        let json = serde_json::to_string(&uid).unwrap();
+
+        assert_eq!(json, "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"");
+        assert_eq!(serde_json::from_str::<Uid<Account>>(&json).unwrap(), uid);
+    }
+
+    #[cfg(feature = "dummy")]
+    #[test]
+    fn dummy_in_derived_struct() {
+        use crate::domain::value_type::amount::Amount;
+        use fake::{Dummy, Fake, Faker};
+
+        #[derive(Debug, Dummy)]
+        struct Payment {
+            id: Uid<Payment>,
+            #[dummy(faker = "Amount::new_scaled_i32(1)..=Amount::new_scaled_i32(10_000)")]
+            amount: Amount<2>,
+        }
+
+        let first: Payment = Faker.fake();
+        let second: Payment = Faker.fake();
+
+        assert_ne!(first.id, second.id);
+        assert!(
+            first
+                .amount
+                .to_minor_units()
+                .is_some_and(|m| (1..=10_000).contains(&m))
+        );
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_is_v4(uid: Uid<Account>) {
+            proptest::prop_assert_eq!(uuid::Uuid::from(uid).get_version_num(), 4);
+        }
+    }
+}
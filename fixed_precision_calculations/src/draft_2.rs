@@ -1,7 +1,9 @@
 use fastnum::D128;
 use std::fmt::Formatter;
 
+#[derive(Default)]
 pub struct CurrencyFormatter {}
+
 impl CurrencyFormatter {
     pub fn new() -> Self {
         Self {}
@@ -0,0 +1,653 @@
+//! ISO 20022 pain.001 (customer credit transfer initiation) generation for SEPA credit transfers.
+//!
+//! A [`CreditTransfer`] is one batch of EUR payments from a single debtor account, written as one
+//! `<PmtInf>` block in either the `pain.001.001.03` or `pain.001.001.09` schema. Amounts are
+//! validated against the SEPA rules, and the control sums are exact [`Amount`] sums, so they always
+//! match the amounts written. Text is XML-escaped but not transliterated to the SEPA character set.
+
+use crate::domain::value_type::amount::Amount;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::fmt::Write;
+
+/// The largest amount of a SEPA credit transfer, 999 999 999.99 EUR.
+pub const MAX_AMOUNT: Amount<2> = Amount::new_scaled_i64(99_999_999_999);
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Pain001Error {
+    #[error("a credit transfer needs at least one payment")]
+    NoPayments,
+    #[error("<{element}>: {kind}")]
+    Header {
+        element: &'static str,
+        kind: ValueError,
+    },
+    /// A field of the payment at 1-based `index`.
+    #[error("payment {index}, <{element}>: {kind}")]
+    Payment {
+        index: usize,
+        element: &'static str,
+        kind: ValueError,
+    },
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ValueError {
+    #[error("amount {0} is not positive")]
+    NotPositive(Amount<2>),
+    #[error("amount {amount} has {found} decimals, expected at most 2")]
+    Decimals { amount: Amount<2>, found: i16 },
+    #[error("amount {0} exceeds the SEPA maximum of 999999999.99")]
+    TooLarge(Amount<2>),
+    #[error("invalid IBAN '{0}'")]
+    Iban(String),
+    #[error("invalid BIC '{0}'")]
+    Bic(String),
+    #[error("'{value}' must have 1 to {max} characters")]
+    Length { value: String, max: usize },
+}
+
+/// The pain.001 schema version to write.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Pain001Version {
+    /// `pain.001.001.03`, the version most banks accept.
+    #[default]
+    V03,
+    /// `pain.001.001.09`, required by the 2023 SEPA rulebooks.
+    V09,
+}
+
+/// An account holder: the debtor or a creditor.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Party {
+    /// At most 70 characters.
+    pub name: String,
+    /// Spaces and lower case are allowed, the IBAN is written normalized.
+    pub iban: String,
+    /// Optional within SEPA, where the IBAN identifies the bank.
+    pub bic: Option<String>,
+}
+
+/// One credit transfer to a creditor, in EUR.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Payment {
+    /// `<EndToEndId>`, passed on to the creditor; at most 35 characters.
+    pub end_to_end_id: String,
+    pub creditor: Party,
+    /// Positive, with at most 2 decimals and at most [`MAX_AMOUNT`].
+    pub amount: Amount<2>,
+    /// `<Ustrd>`, e.g. an invoice number; at most 140 characters.
+    pub remittance_information: Option<String>,
+}
+
+/// A batch of SEPA credit transfers from one debtor account, executed on the same date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreditTransfer {
+    /// `<MsgId>`, unique per debtor and also used as `<PmtInfId>`; at most 35 characters.
+    pub message_id: String,
+    pub created_at: NaiveDateTime,
+    pub execution_date: NaiveDate,
+    pub debtor: Party,
+    pub payments: Vec<Payment>,
+}
+
+impl Pain001Version {
+    pub const fn namespace(&self) -> &'static str {
+        match self {
+            Self::V03 => "urn:iso:std:iso:20022:tech:xsd:pain.001.001.03",
+            Self::V09 => "urn:iso:std:iso:20022:tech:xsd:pain.001.001.09",
+        }
+    }
+
+    const fn bic_element(&self) -> &'static str {
+        match self {
+            Self::V03 => "BIC",
+            Self::V09 => "BICFI",
+        }
+    }
+}
+
+impl CreditTransfer {
+    /// The sum of all payment amounts, written as `<CtrlSum>`.
+    pub fn control_sum(&self) -> Amount<2> {
+        self.payments.iter().map(|payment| payment.amount).sum()
+    }
+
+    /// Checks every field against the SEPA rules, reporting the first invalid one.
+    pub fn validate(&self) -> Result<(), Pain001Error> {
+        if self.payments.is_empty() {
+            return Err(Pain001Error::NoPayments);
+        }
+
+        let header = |element, kind| Pain001Error::Header { element, kind };
+        check_text(&self.message_id, 35).map_err(|kind| header("MsgId", kind))?;
+        check_party(&self.debtor).map_err(|(element, kind)| header(element, kind))?;
+
+        for (index, payment) in self.payments.iter().enumerate() {
+            let invalid = |element, kind| Pain001Error::Payment {
+                index: index + 1,
+                element,
+                kind,
+            };
+            check_text(&payment.end_to_end_id, 35).map_err(|kind| invalid("EndToEndId", kind))?;
+            check_amount(payment.amount).map_err(|kind| invalid("InstdAmt", kind))?;
+            check_party(&payment.creditor).map_err(|(element, kind)| invalid(element, kind))?;
+            if let Some(remittance_information) = &payment.remittance_information {
+                check_text(remittance_information, 140).map_err(|kind| invalid("Ustrd", kind))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the transfer and writes it as a pain.001 document.
+    ///
+    /// ```
+    /// use fixed_precision_calculations::export::pain001::{CreditTransfer, Pain001Version, Party, Payment};
+    ///
+    /// let transfer = CreditTransfer {
+    ///     message_id: "MSG-1".to_string(),
+    ///     created_at: "2025-01-31T10:00:00".parse().unwrap(),
+    ///     execution_date: "2025-02-03".parse().unwrap(),
+    ///     debtor: Party {
+    ///         name: "ACME GmbH".to_string(),
+    ///         iban: "DE89 3704 0044 0532 0130 00".to_string(),
+    ///         bic: Some("COBADEFFXXX".to_string()),
+    ///     },
+    ///     payments: vec![Payment {
+    ///         end_to_end_id: "INV-42".to_string(),
+    ///         creditor: Party {
+    ///             name: "Supplier SA".to_string(),
+    ///             iban: "FR1420041010050500013M02606".to_string(),
+    ///             bic: None,
+    ///         },
+    ///         amount: "1234.5".parse().unwrap(),
+    ///         remittance_information: Some("Invoice 42".to_string()),
+    ///     }],
+    /// };
+    ///
+    /// let xml = transfer.to_xml(Pain001Version::V03).unwrap();
+    ///
+    /// assert!(xml.contains("<CtrlSum>1234.50</CtrlSum>"));
+    /// assert!(xml.contains("<IBAN>DE89370400440532013000</IBAN>"));
+    /// ```
+    pub fn to_xml(&self, version: Pain001Version) -> Result<String, Pain001Error> {
+        self.validate()?;
+
+        let mut xml = Xml::default();
+        xml.line(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.open(&format!(
+            r#"Document xmlns="{}" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#,
+            version.namespace()
+        ));
+        xml.open("CstmrCdtTrfInitn");
+
+        let transactions = self.payments.len().to_string();
+        let control_sum = format_amount(self.control_sum());
+        xml.open("GrpHdr");
+        xml.leaf("MsgId", &self.message_id);
+        xml.leaf(
+            "CreDtTm",
+            &self.created_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        );
+        xml.leaf("NbOfTxs", &transactions);
+        xml.leaf("CtrlSum", &control_sum);
+        xml.open("InitgPty");
+        xml.leaf("Nm", &self.debtor.name);
+        xml.close("InitgPty");
+        xml.close("GrpHdr");
+
+        xml.open("PmtInf");
+        xml.leaf("PmtInfId", &self.message_id);
+        xml.leaf("PmtMtd", "TRF");
+        xml.leaf("NbOfTxs", &transactions);
+        xml.leaf("CtrlSum", &control_sum);
+        xml.open("PmtTpInf");
+        xml.open("SvcLvl");
+        xml.leaf("Cd", "SEPA");
+        xml.close("SvcLvl");
+        xml.close("PmtTpInf");
+        let execution_date = self.execution_date.format("%Y-%m-%d").to_string();
+        match version {
+            Pain001Version::V03 => xml.leaf("ReqdExctnDt", &execution_date),
+            Pain001Version::V09 => {
+                xml.open("ReqdExctnDt");
+                xml.leaf("Dt", &execution_date);
+                xml.close("ReqdExctnDt");
+            }
+        }
+        xml.party("Dbtr", &self.debtor);
+        xml.open("DbtrAgt");
+        xml.open("FinInstnId");
+        match &self.debtor.bic {
+            Some(bic) => xml.leaf(version.bic_element(), &normalize(bic)),
+            None => {
+                xml.open("Othr");
+                xml.leaf("Id", "NOTPROVIDED");
+                xml.close("Othr");
+            }
+        }
+        xml.close("FinInstnId");
+        xml.close("DbtrAgt");
+        xml.leaf("ChrgBr", "SLEV");
+
+        for payment in &self.payments {
+            xml.open("CdtTrfTxInf");
+            xml.open("PmtId");
+            xml.leaf("EndToEndId", &payment.end_to_end_id);
+            xml.close("PmtId");
+            xml.open("Amt");
+            xml.line(&format!(
+                r#"<InstdAmt Ccy="EUR">{}</InstdAmt>"#,
+                format_amount(payment.amount)
+            ));
+            xml.close("Amt");
+            if let Some(bic) = &payment.creditor.bic {
+                xml.open("CdtrAgt");
+                xml.open("FinInstnId");
+                xml.leaf(version.bic_element(), &normalize(bic));
+                xml.close("FinInstnId");
+                xml.close("CdtrAgt");
+            }
+            xml.party("Cdtr", &payment.creditor);
+            if let Some(remittance_information) = &payment.remittance_information {
+                xml.open("RmtInf");
+                xml.leaf("Ustrd", remittance_information);
+                xml.close("RmtInf");
+            }
+            xml.close("CdtTrfTxInf");
+        }
+
+        xml.close("PmtInf");
+        xml.close("CstmrCdtTrfInitn");
+        xml.close("Document");
+        Ok(xml.out)
+    }
+}
+
+/// An indenting XML writer for the fixed pain.001 layout.
+#[derive(Debug, Default)]
+struct Xml {
+    out: String,
+    depth: usize,
+}
+
+impl Xml {
+    fn line(&mut self, text: &str) {
+        let _ = writeln!(self.out, "{:indent$}{text}", "", indent = self.depth * 2);
+    }
+
+    /// Starts an element; `tag` may carry attributes.
+    fn open(&mut self, tag: &str) {
+        self.line(&format!("<{tag}>"));
+        self.depth += 1;
+    }
+
+    fn close(&mut self, element: &str) {
+        self.depth -= 1;
+        self.line(&format!("</{element}>"));
+    }
+
+    fn leaf(&mut self, element: &str, text: &str) {
+        self.line(&format!("<{element}>{}</{element}>", escape(text)));
+    }
+
+    /// `<Dbtr>` or `<Cdtr>` with its name, followed by the `Acct` element with its IBAN.
+    fn party(&mut self, element: &str, party: &Party) {
+        self.open(element);
+        self.leaf("Nm", &party.name);
+        self.close(element);
+
+        let account = format!("{element}Acct");
+        self.open(&account);
+        self.open("Id");
+        self.leaf("IBAN", &normalize(&party.iban));
+        self.close("Id");
+        self.close(&account);
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+/// Always two decimals, e.g. `12.30`; the amount is validated to hold at most two.
+fn format_amount(amount: Amount<2>) -> String {
+    amount.raw().rescale(2).to_string()
+}
+
+/// Upper case without whitespace, as IBANs and BICs are written.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|char| !char.is_whitespace())
+        .map(|char| char.to_ascii_uppercase())
+        .collect()
+}
+
+fn check_text(text: &str, max: usize) -> Result<(), ValueError> {
+    match text.trim().is_empty() || text.chars().count() > max {
+        true => Err(ValueError::Length {
+            value: text.to_string(),
+            max,
+        }),
+        false => Ok(()),
+    }
+}
+
+fn check_amount(amount: Amount<2>) -> Result<(), ValueError> {
+    if amount.is_zero() || amount.is_negative() {
+        return Err(ValueError::NotPositive(amount));
+    }
+    if amount.decimals() > 2 {
+        return Err(ValueError::Decimals {
+            amount,
+            found: amount.decimals(),
+        });
+    }
+    if amount > MAX_AMOUNT {
+        return Err(ValueError::TooLarge(amount));
+    }
+    Ok(())
+}
+
+/// The element of the first invalid field of `party`, with what is wrong with it.
+fn check_party(party: &Party) -> Result<(), (&'static str, ValueError)> {
+    check_text(&party.name, 70).map_err(|kind| ("Nm", kind))?;
+    if !is_iban(&normalize(&party.iban)) {
+        return Err(("IBAN", ValueError::Iban(party.iban.clone())));
+    }
+    if let Some(bic) = &party.bic
+        && !is_bic(&normalize(bic))
+    {
+        return Err(("BIC", ValueError::Bic(bic.clone())));
+    }
+    Ok(())
+}
+
+/// Whether `iban` has a country code, check digits and a BBAN that pass the ISO 13616 mod 97 check.
+fn is_iban(iban: &str) -> bool {
+    let bytes = iban.as_bytes();
+    if !(15..=34).contains(&bytes.len())
+        || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+        || !bytes[2..4].iter().all(u8::is_ascii_digit)
+        || !bytes.iter().all(u8::is_ascii_alphanumeric)
+    {
+        return false;
+    }
+
+    let remainder =
+        bytes[4..]
+            .iter()
+            .chain(&bytes[..4])
+            .fold(0_u32, |remainder, &byte| match byte {
+                b'0'..=b'9' => (remainder * 10 + u32::from(byte - b'0')) % 97,
+                _ => (remainder * 100 + u32::from(byte - b'A' + 10)) % 97,
+            });
+    remainder == 1
+}
+
+/// Whether `bic` is an 8 or 11 character ISO 9362 business identifier code.
+fn is_bic(bic: &str) -> bool {
+    let bytes = bic.as_bytes();
+    matches!(bytes.len(), 8 | 11)
+        && bytes[..6].iter().all(u8::is_ascii_uppercase)
+        && bytes[6..].iter().all(u8::is_ascii_alphanumeric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CreditTransfer, MAX_AMOUNT, Pain001Error, Pain001Version, Party, Payment, ValueError,
+        is_iban,
+    };
+    use crate::domain::value_type::amount::Amount;
+    use rstest::rstest;
+
+    const V03: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:pain.001.001.03" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <CstmrCdtTrfInitn>
+    <GrpHdr>
+      <MsgId>MSG-2025-01</MsgId>
+      <CreDtTm>2025-01-31T10:15:00</CreDtTm>
+      <NbOfTxs>2</NbOfTxs>
+      <CtrlSum>1234.80</CtrlSum>
+      <InitgPty>
+        <Nm>ACME GmbH</Nm>
+      </InitgPty>
+    </GrpHdr>
+    <PmtInf>
+      <PmtInfId>MSG-2025-01</PmtInfId>
+      <PmtMtd>TRF</PmtMtd>
+      <NbOfTxs>2</NbOfTxs>
+      <CtrlSum>1234.80</CtrlSum>
+      <PmtTpInf>
+        <SvcLvl>
+          <Cd>SEPA</Cd>
+        </SvcLvl>
+      </PmtTpInf>
+      <ReqdExctnDt>2025-02-03</ReqdExctnDt>
+      <Dbtr>
+        <Nm>ACME GmbH</Nm>
+      </Dbtr>
+      <DbtrAcct>
+        <Id>
+          <IBAN>DE89370400440532013000</IBAN>
+        </Id>
+      </DbtrAcct>
+      <DbtrAgt>
+        <FinInstnId>
+          <BIC>COBADEFFXXX</BIC>
+        </FinInstnId>
+      </DbtrAgt>
+      <ChrgBr>SLEV</ChrgBr>
+      <CdtTrfTxInf>
+        <PmtId>
+          <EndToEndId>INV-42</EndToEndId>
+        </PmtId>
+        <Amt>
+          <InstdAmt Ccy="EUR">1234.50</InstdAmt>
+        </Amt>
+        <CdtrAgt>
+          <FinInstnId>
+            <BIC>BNPAFRPP</BIC>
+          </FinInstnId>
+        </CdtrAgt>
+        <Cdtr>
+          <Nm>Dupont &amp; Fils</Nm>
+        </Cdtr>
+        <CdtrAcct>
+          <Id>
+            <IBAN>FR1420041010050500013M02606</IBAN>
+          </Id>
+        </CdtrAcct>
+        <RmtInf>
+          <Ustrd>Invoice &lt;42&gt;</Ustrd>
+        </RmtInf>
+      </CdtTrfTxInf>
+      <CdtTrfTxInf>
+        <PmtId>
+          <EndToEndId>INV-43</EndToEndId>
+        </PmtId>
+        <Amt>
+          <InstdAmt Ccy="EUR">0.30</InstdAmt>
+        </Amt>
+        <Cdtr>
+          <Nm>Müller</Nm>
+        </Cdtr>
+        <CdtrAcct>
+          <Id>
+            <IBAN>AT611904300234573201</IBAN>
+          </Id>
+        </CdtrAcct>
+      </CdtTrfTxInf>
+    </PmtInf>
+  </CstmrCdtTrfInitn>
+</Document>
+"#;
+
+    fn party(name: &str, iban: &str, bic: Option<&str>) -> Party {
+        Party {
+            name: name.to_string(),
+            iban: iban.to_string(),
+            bic: bic.map(str::to_string),
+        }
+    }
+
+    fn transfer() -> CreditTransfer {
+        CreditTransfer {
+            message_id: "MSG-2025-01".to_string(),
+            created_at: "2025-01-31T10:15:00".parse().unwrap(),
+            execution_date: "2025-02-03".parse().unwrap(),
+            debtor: party(
+                "ACME GmbH",
+                "de89 3704 0044 0532 0130 00",
+                Some("COBADEFFXXX"),
+            ),
+            payments: vec![
+                Payment {
+                    end_to_end_id: "INV-42".to_string(),
+                    creditor: party(
+                        "Dupont & Fils",
+                        "FR14 2004 1010 0505 0001 3M02 606",
+                        Some("bnpa frpp"),
+                    ),
+                    amount: Amount::new_scaled_i32(123_450),
+                    remittance_information: Some("Invoice <42>".to_string()),
+                },
+                Payment {
+                    end_to_end_id: "INV-43".to_string(),
+                    creditor: party("Müller", "AT611904300234573201", None),
+                    // 0.1 + 0.2 as decimals, so the control sum is exact.
+                    amount: "0.1".parse::<Amount<2>>().unwrap() + "0.2".parse().unwrap(),
+                    remittance_information: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn v03() {
+        assert_eq!(transfer().to_xml(Pain001Version::V03).unwrap(), V03);
+    }
+
+    #[test]
+    fn v09() {
+        let expected = V03
+            .replace("pain.001.001.03", "pain.001.001.09")
+            .replace(
+                "<ReqdExctnDt>2025-02-03</ReqdExctnDt>",
+                "<ReqdExctnDt>\n        <Dt>2025-02-03</Dt>\n      </ReqdExctnDt>",
+            )
+            .replace("<BIC>", "<BICFI>")
+            .replace("</BIC>", "</BICFI>");
+
+        assert_eq!(transfer().to_xml(Pain001Version::V09).unwrap(), expected);
+    }
+
+    #[test]
+    fn debtor_without_bic() {
+        let mut transfer = transfer();
+        transfer.debtor.bic = None;
+
+        let xml = transfer.to_xml(Pain001Version::V03).unwrap();
+
+        assert!(xml.contains("<Othr>\n            <Id>NOTPROVIDED</Id>"));
+    }
+
+    #[rstest]
+    #[case(Amount::ZERO, ValueError::NotPositive(Amount::ZERO))]
+    #[case(-Amount::new_scaled_i32(1), ValueError::NotPositive(-Amount::new_scaled_i32(1)))]
+    #[case(
+        Amount::new_f64(0.125),
+        ValueError::Decimals { amount: Amount::new_f64(0.125), found: 3 }
+    )]
+    #[case(
+        MAX_AMOUNT + Amount::new_scaled_i32(1),
+        ValueError::TooLarge(MAX_AMOUNT + Amount::new_scaled_i32(1))
+    )]
+    fn rejects_amounts(#[case] amount: Amount<2>, #[case] expected: ValueError) {
+        let mut transfer = transfer();
+        transfer.payments[1].amount = amount;
+
+        assert_eq!(
+            transfer.to_xml(Pain001Version::V03),
+            Err(Pain001Error::Payment {
+                index: 2,
+                element: "InstdAmt",
+                kind: expected,
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_maximum() {
+        let mut transfer = transfer();
+        transfer.payments = vec![transfer.payments[0].clone(); 2];
+        transfer.payments[0].amount = MAX_AMOUNT;
+
+        let xml = transfer.to_xml(Pain001Version::V03).unwrap();
+
+        assert!(xml.contains("<InstdAmt Ccy=\"EUR\">999999999.99</InstdAmt>"));
+        assert!(xml.contains("<CtrlSum>1000001234.49</CtrlSum>"));
+    }
+
+    #[test]
+    fn rejects_fields() {
+        let mut transfer = transfer();
+        transfer.payments.clear();
+        assert_eq!(transfer.validate(), Err(Pain001Error::NoPayments));
+
+        let mut transfer = self::transfer();
+        transfer.message_id = "M".repeat(36);
+        assert_eq!(
+            transfer.validate(),
+            Err(Pain001Error::Header {
+                element: "MsgId",
+                kind: ValueError::Length {
+                    value: "M".repeat(36),
+                    max: 35,
+                },
+            })
+        );
+
+        let mut transfer = self::transfer();
+        transfer.debtor.bic = Some("COBADE".to_string());
+        assert_eq!(
+            transfer.validate(),
+            Err(Pain001Error::Header {
+                element: "BIC",
+                kind: ValueError::Bic("COBADE".to_string()),
+            })
+        );
+
+        let mut transfer = self::transfer();
+        transfer.payments[0].creditor.iban = "FR1520041010050500013M02606".to_string();
+        assert_eq!(
+            transfer.validate(),
+            Err(Pain001Error::Payment {
+                index: 1,
+                element: "IBAN",
+                kind: ValueError::Iban("FR1520041010050500013M02606".to_string()),
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("GB82WEST12345698765432", true)]
+    #[case("NL91ABNA0417164300", true)]
+    #[case("GB82WEST12345698765431", false)]
+    #[case("GB82 WEST", false)]
+    #[case("1282WEST12345698765432", false)]
+    fn ibans(#[case] iban: &str, #[case] valid: bool) {
+        assert_eq!(is_iban(iban), valid);
+    }
+}
@@ -0,0 +1,727 @@
+//! ISO 20022 CAMT.053 (bank to customer statement) parser.
+//!
+//! Elements are matched by their local name, so every `camt.053.001.xx` version parses. Amounts are
+//! read with [`Amount::from_str`](Amount#method.from_str). Each statement is checked to balance, see
+//! [`Statement::validate`].
+
+use crate::domain::transaction::Direction;
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::money::Money;
+use crate::ingest::statement::{
+    FieldError, Unbalanced, ValueError, check_continuity, signed_amount,
+};
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CamtError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Xml(#[from] roxmltree::Error),
+    #[error("expected a <Document> with <BkToCstmrStmt>, found <{0}>")]
+    NotCamt053(String),
+    #[error(transparent)]
+    Element(#[from] FieldError),
+    #[error("statement '{statement}' has no {kind} balance")]
+    MissingBalance {
+        statement: String,
+        kind: &'static str,
+    },
+    #[error(transparent)]
+    Unbalanced(#[from] Unbalanced),
+}
+
+/// One `<Stmt>` of a CAMT.053 document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement<const DECIMALS: usize> {
+    /// `<Id>`, unique per account servicer.
+    pub id: String,
+    pub account: Account,
+    /// The opening booked (`OPBD`) or previously closed booked (`PRCD`) balance.
+    pub opening: Balance<DECIMALS>,
+    /// The closing booked (`CLBD`) balance.
+    pub closing: Balance<DECIMALS>,
+    /// Every balance of the statement in document order, including the opening and closing ones.
+    pub balances: Vec<Balance<DECIMALS>>,
+    pub entries: Vec<Entry<DECIMALS>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Account {
+    pub iban: Option<String>,
+    /// `<Othr><Id>`, for accounts without an IBAN.
+    pub other_id: Option<String>,
+    /// The currency of every amount in the statement.
+    pub currency: Currency,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BalanceType {
+    /// `OPBD`
+    OpeningBooked,
+    /// `PRCD`, used by some banks instead of an opening balance.
+    PreviouslyClosedBooked,
+    /// `CLBD`
+    ClosingBooked,
+    /// `CLAV`
+    ClosingAvailable,
+    /// `FWAV`
+    ForwardAvailable,
+    /// `ITBD`
+    InterimBooked,
+    Other(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Balance<const DECIMALS: usize> {
+    pub kind: BalanceType,
+    /// Negative for a debit balance.
+    pub amount: Money<DECIMALS>,
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum EntryStatus {
+    /// `BOOK`
+    Booked,
+    /// `PDNG`
+    Pending,
+    /// `INFO`
+    Information,
+    Other(String),
+}
+
+/// One `<Ntry>` of a statement.
+///
+/// `amount` is always the non-negative magnitude; the sign follows from `direction`, see
+/// [`Entry::signed_amount`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry<const DECIMALS: usize> {
+    /// `<NtryRef>`
+    pub reference: Option<String>,
+    pub amount: Money<DECIMALS>,
+    pub direction: Direction,
+    /// `<RvslInd>`, set when the entry reverses an earlier one.
+    pub reversal: bool,
+    pub status: EntryStatus,
+    pub booking_date: Option<NaiveDate>,
+    pub value_date: Option<NaiveDate>,
+    /// `<AcctSvcrRef>`, the bank's own reference.
+    pub account_servicer_reference: Option<String>,
+    /// `<EndToEndId>` of each transaction in `<NtryDtls>`, except `NOTPROVIDED`.
+    pub end_to_end_ids: Vec<String>,
+    /// Unstructured remittance information (`<Ustrd>`) of each transaction in `<NtryDtls>`.
+    pub remittance_information: Vec<String>,
+}
+
+/// Reads every statement of the CAMT.053 file at `path`.
+pub fn read_statements<const DECIMALS: usize>(
+    path: impl AsRef<Path>,
+) -> Result<Vec<Statement<DECIMALS>>, CamtError> {
+    parse_statements(&std::fs::read_to_string(path)?)
+}
+
+/// Parses every statement of a CAMT.053 document, failing on the first invalid element or on a
+/// statement that does not balance.
+///
+/// ```
+/// use fixed_precision_calculations::ingest::camt053::parse_statements;
+///
+/// let xml = r#"<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.08"><BkToCstmrStmt>
+///   <Stmt>
+///     <Id>2025-01</Id>
+///     <Acct><Id><IBAN>CH9300762011623852957</IBAN></Id><Ccy>CHF</Ccy></Acct>
+///     <Bal><Tp><CdOrPrtry><Cd>OPBD</Cd></CdOrPrtry></Tp><Amt Ccy="CHF">100.00</Amt>
+///       <CdtDbtInd>CRDT</CdtDbtInd><Dt><Dt>2025-01-01</Dt></Dt></Bal>
+///     <Bal><Tp><CdOrPrtry><Cd>CLBD</Cd></CdOrPrtry></Tp><Amt Ccy="CHF">87.66</Amt>
+///       <CdtDbtInd>CRDT</CdtDbtInd><Dt><Dt>2025-01-31</Dt></Dt></Bal>
+///     <Ntry><Amt Ccy="CHF">12.34</Amt><CdtDbtInd>DBIT</CdtDbtInd><Sts><Cd>BOOK</Cd></Sts>
+///       <BookgDt><Dt>2025-01-15</Dt></BookgDt></Ntry>
+///   </Stmt>
+/// </BkToCstmrStmt></Document>"#;
+///
+/// let statements = parse_statements::<2>(xml).unwrap();
+///
+/// assert_eq!(statements[0].entries[0].signed_amount().to_string(), "-12.34 CHF");
+/// ```
+pub fn parse_statements<const DECIMALS: usize>(
+    xml: &str,
+) -> Result<Vec<Statement<DECIMALS>>, CamtError> {
+    let document = Document::parse(xml)?;
+    let root = document.root_element();
+    let message = child(root, "BkToCstmrStmt")
+        .filter(|_| root.tag_name().name() == "Document")
+        .ok_or_else(|| CamtError::NotCamt053(root.tag_name().name().to_string()))?;
+
+    let parser = Parser {
+        document: &document,
+    };
+    children(message, "Stmt")
+        .map(|statement| {
+            let statement = parser.statement(statement)?;
+            statement.validate()?;
+            Ok(statement)
+        })
+        .collect()
+}
+
+impl<const DECIMALS: usize> Statement<DECIMALS> {
+    /// Checks that the opening balance plus the booked entries equals the closing balance. Pending
+    /// and information entries are not part of the booked balance.
+    pub fn validate(&self) -> Result<(), Unbalanced> {
+        check_continuity(
+            &self.id,
+            self.opening.amount,
+            self.entries
+                .iter()
+                .filter(|entry| entry.status == EntryStatus::Booked)
+                .map(|entry| entry.signed_amount().amount()),
+            self.closing.amount,
+        )
+    }
+}
+
+impl<const DECIMALS: usize> Entry<DECIMALS> {
+    pub fn signed_amount(&self) -> Money<DECIMALS> {
+        signed_amount(self.amount, self.direction)
+    }
+}
+
+impl From<&str> for BalanceType {
+    fn from(code: &str) -> Self {
+        match code {
+            "OPBD" => Self::OpeningBooked,
+            "PRCD" => Self::PreviouslyClosedBooked,
+            "CLBD" => Self::ClosingBooked,
+            "CLAV" => Self::ClosingAvailable,
+            "FWAV" => Self::ForwardAvailable,
+            "ITBD" => Self::InterimBooked,
+            code => Self::Other(code.to_string()),
+        }
+    }
+}
+
+impl From<&str> for EntryStatus {
+    fn from(code: &str) -> Self {
+        match code {
+            "BOOK" => Self::Booked,
+            "PDNG" => Self::Pending,
+            "INFO" => Self::Information,
+            code => Self::Other(code.to_string()),
+        }
+    }
+}
+
+struct Parser<'a, 'input> {
+    document: &'a Document<'input>,
+}
+
+impl Parser<'_, '_> {
+    fn statement<const DECIMALS: usize>(
+        &self,
+        node: Node,
+    ) -> Result<Statement<DECIMALS>, CamtError> {
+        let id = self.text(self.required(node, "Id")?).to_string();
+        let account = self.account(self.required(node, "Acct")?)?;
+
+        let balances = children(node, "Bal")
+            .map(|balance| self.balance(balance, account.currency))
+            .collect::<Result<Vec<_>, _>>()?;
+        let find = |kinds: &[BalanceType], kind| {
+            balances
+                .iter()
+                .find(|balance| kinds.contains(&balance.kind))
+                .cloned()
+                .ok_or_else(|| CamtError::MissingBalance {
+                    statement: id.clone(),
+                    kind,
+                })
+        };
+        let opening = find(
+            &[
+                BalanceType::OpeningBooked,
+                BalanceType::PreviouslyClosedBooked,
+            ],
+            "opening booked",
+        )?;
+        let closing = find(&[BalanceType::ClosingBooked], "closing booked")?;
+
+        let entries = children(node, "Ntry")
+            .map(|entry| self.entry(entry, account.currency))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Statement {
+            id,
+            account,
+            opening,
+            closing,
+            balances,
+            entries,
+        })
+    }
+
+    /// The account currency is `<Ccy>`, or else that of the first balance.
+    fn account(&self, node: Node) -> Result<Account, CamtError> {
+        let id = self.required(node, "Id")?;
+        let currency = match child(node, "Ccy") {
+            Some(currency) => self.parse(currency, self.text(currency))?,
+            None => {
+                let balance = child(node.parent_element().unwrap_or(node), "Bal")
+                    .ok_or_else(|| self.error(node, ValueError::Missing("Ccy")))?;
+                let amount = self.required(balance, "Amt")?;
+                self.parse(amount, self.currency_attribute(amount)?)?
+            }
+        };
+
+        Ok(Account {
+            iban: child(id, "IBAN").map(|iban| self.text(iban).to_string()),
+            other_id: path(id, &["Othr", "Id"]).map(|other| self.text(other).to_string()),
+            currency,
+        })
+    }
+
+    fn balance<const DECIMALS: usize>(
+        &self,
+        node: Node,
+        currency: Currency,
+    ) -> Result<Balance<DECIMALS>, CamtError> {
+        let code = path(node, &["Tp", "CdOrPrtry", "Cd"])
+            .or_else(|| path(node, &["Tp", "CdOrPrtry", "Prtry"]))
+            .ok_or_else(|| self.error(node, ValueError::Missing("Tp")))?;
+        let amount = self.amount(node, currency)?;
+
+        Ok(Balance {
+            kind: BalanceType::from(self.text(code)),
+            amount: match self.direction(node)? {
+                Direction::Debit => -amount,
+                Direction::Credit => amount,
+            },
+            date: self.date(self.required(node, "Dt")?)?,
+        })
+    }
+
+    fn entry<const DECIMALS: usize>(
+        &self,
+        node: Node,
+        currency: Currency,
+    ) -> Result<Entry<DECIMALS>, CamtError> {
+        let status = self.required(node, "Sts")?;
+        // A code element since camt.053.001.08, plain text before.
+        let status = child(status, "Cd").unwrap_or(status);
+        let transactions = path(node, &["NtryDtls"])
+            .map(|details| children(details, "TxDtls").collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        Ok(Entry {
+            reference: child(node, "NtryRef").map(|reference| self.text(reference).to_string()),
+            amount: self.amount(node, currency)?,
+            direction: self.direction(node)?,
+            reversal: child(node, "RvslInd").is_some_and(|reversal| self.text(reversal) == "true"),
+            status: EntryStatus::from(self.text(status)),
+            booking_date: child(node, "BookgDt")
+                .map(|date| self.date(date))
+                .transpose()?,
+            value_date: child(node, "ValDt")
+                .map(|date| self.date(date))
+                .transpose()?,
+            account_servicer_reference: child(node, "AcctSvcrRef")
+                .map(|reference| self.text(reference).to_string()),
+            end_to_end_ids: transactions
+                .iter()
+                .filter_map(|transaction| path(*transaction, &["Refs", "EndToEndId"]))
+                .map(|id| self.text(id))
+                .filter(|id| *id != "NOTPROVIDED")
+                .map(str::to_string)
+                .collect(),
+            remittance_information: transactions
+                .iter()
+                .filter_map(|transaction| child(*transaction, "RmtInf"))
+                .flat_map(|remittance| children(remittance, "Ustrd"))
+                .map(|unstructured| self.text(unstructured).to_string())
+                .collect(),
+        })
+    }
+
+    /// The non-negative `<Amt>` of a balance or entry, in the account currency.
+    fn amount<const DECIMALS: usize>(
+        &self,
+        parent: Node,
+        currency: Currency,
+    ) -> Result<Money<DECIMALS>, CamtError> {
+        let node = self.required(parent, "Amt")?;
+        let text = self.text(node);
+        if text.starts_with(['-', '+']) {
+            return Err(self.invalid(node, text, "an unsigned amount"));
+        }
+        let amount: Amount<DECIMALS> = self.parse(node, text)?;
+
+        let found: Currency = self.parse(node, self.currency_attribute(node)?)?;
+        if found != currency {
+            return Err(self.error(
+                node,
+                ValueError::CurrencyMismatch {
+                    expected: currency,
+                    found,
+                },
+            ));
+        }
+
+        Ok(Money::new(amount, currency))
+    }
+
+    fn currency_attribute<'a>(&self, node: Node<'a, '_>) -> Result<&'a str, CamtError> {
+        node.attribute("Ccy")
+            .ok_or_else(|| self.error(node, ValueError::Missing("Ccy")))
+    }
+
+    fn direction(&self, parent: Node) -> Result<Direction, CamtError> {
+        let node = self.required(parent, "CdtDbtInd")?;
+        match self.text(node) {
+            "CRDT" => Ok(Direction::Credit),
+            "DBIT" => Ok(Direction::Debit),
+            value => Err(self.invalid(node, value, "CRDT or DBIT")),
+        }
+    }
+
+    /// A `<Dt>` date or the date of a `<DtTm>` date and time.
+    fn date(&self, parent: Node) -> Result<NaiveDate, CamtError> {
+        let node = child(parent, "Dt")
+            .or_else(|| child(parent, "DtTm"))
+            .ok_or_else(|| self.error(parent, ValueError::Missing("Dt")))?;
+        let value = self.text(node);
+
+        value
+            .get(..10)
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .ok_or_else(|| self.invalid(node, value, "an ISO 8601 date"))
+    }
+
+    fn parse<T>(&self, node: Node, value: &str) -> Result<T, CamtError>
+    where
+        T: std::str::FromStr,
+        T::Err: Into<ValueError>,
+    {
+        value
+            .parse()
+            .map_err(|error: T::Err| self.error(node, error.into()))
+    }
+
+    fn required<'a, 'input>(
+        &self,
+        parent: Node<'a, 'input>,
+        name: &'static str,
+    ) -> Result<Node<'a, 'input>, CamtError> {
+        child(parent, name).ok_or_else(|| self.error(parent, ValueError::Missing(name)))
+    }
+
+    fn text<'a>(&self, node: Node<'a, '_>) -> &'a str {
+        node.text().unwrap_or_default().trim()
+    }
+
+    fn invalid(&self, node: Node, value: &str, expected: &'static str) -> CamtError {
+        self.error(node, ValueError::invalid(value, expected))
+    }
+
+    fn error(&self, node: Node, kind: ValueError) -> CamtError {
+        let line = self.document.text_pos_at(node.range().start).row;
+
+        FieldError::new(line.into(), format!("<{}>", node.tag_name().name()), kind).into()
+    }
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+fn path<'a, 'input>(node: Node<'a, 'input>, names: &[&str]) -> Option<Node<'a, 'input>> {
+    names.iter().try_fold(node, |node, name| child(node, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BalanceType, CamtError, EntryStatus, FieldError, ValueError, parse_statements,
+        read_statements,
+    };
+    use crate::domain::transaction::Direction;
+    use crate::domain::value_type::amount::{Amount, ParseAmountError};
+    use crate::domain::value_type::currency::Currency;
+    use crate::ingest::test_support::{date, eur};
+
+    const STATEMENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.08">
+  <BkToCstmrStmt>
+    <GrpHdr>
+      <MsgId>MSG-2025-01</MsgId>
+      <CreDtTm>2025-02-01T06:00:00+01:00</CreDtTm>
+    </GrpHdr>
+    <Stmt>
+      <Id>STMT-2025-01</Id>
+      <Acct>
+        <Id><IBAN>DE89370400440532013000</IBAN></Id>
+        <Ccy>EUR</Ccy>
+      </Acct>
+      <Bal>
+        <Tp><CdOrPrtry><Cd>PRCD</Cd></CdOrPrtry></Tp>
+        <Amt Ccy="EUR">1000.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Dt><Dt>2024-12-31</Dt></Dt>
+      </Bal>
+      <Bal>
+        <Tp><CdOrPrtry><Cd>CLBD</Cd></CdOrPrtry></Tp>
+        <Amt Ccy="EUR">3115.51</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Dt><Dt>2025-01-31</Dt></Dt>
+      </Bal>
+      <Bal>
+        <Tp><CdOrPrtry><Cd>CLAV</Cd></CdOrPrtry></Tp>
+        <Amt Ccy="EUR">3015.51</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Dt><DtTm>2025-01-31T23:59:59</DtTm></Dt>
+      </Bal>
+      <Ntry>
+        <NtryRef>1</NtryRef>
+        <Amt Ccy="EUR">2500.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts><Cd>BOOK</Cd></Sts>
+        <BookgDt><Dt>2025-01-27</Dt></BookgDt>
+        <ValDt><Dt>2025-01-28</Dt></ValDt>
+        <AcctSvcrRef>BANK-0001</AcctSvcrRef>
+        <NtryDtls>
+          <TxDtls>
+            <Refs><EndToEndId>SALARY-2025-01</EndToEndId></Refs>
+            <RmtInf><Ustrd>Salary January</Ustrd></RmtInf>
+          </TxDtls>
+        </NtryDtls>
+      </Ntry>
+      <Ntry>
+        <NtryRef>2</NtryRef>
+        <Amt Ccy="EUR">384.49</Amt>
+        <CdtDbtInd>DBIT</CdtDbtInd>
+        <Sts><Cd>BOOK</Cd></Sts>
+        <BookgDt><DtTm>2025-01-30T10:15:00+01:00</DtTm></BookgDt>
+        <ValDt><Dt>2025-01-30</Dt></ValDt>
+        <NtryDtls>
+          <TxDtls>
+            <Refs><EndToEndId>INV-42</EndToEndId></Refs>
+            <RmtInf><Ustrd>Invoice 42</Ustrd></RmtInf>
+          </TxDtls>
+          <TxDtls>
+            <Refs><EndToEndId>NOTPROVIDED</EndToEndId></Refs>
+            <RmtInf><Ustrd>Invoice 43</Ustrd></RmtInf>
+          </TxDtls>
+        </NtryDtls>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="EUR">100.00</Amt>
+        <CdtDbtInd>DBIT</CdtDbtInd>
+        <Sts><Cd>PDNG</Cd></Sts>
+      </Ntry>
+    </Stmt>
+  </BkToCstmrStmt>
+</Document>
+"#;
+
+    fn element_error(xml: &str) -> FieldError {
+        match parse_statements::<2>(xml) {
+            Err(CamtError::Element(error)) => error,
+            result => panic!("expected an element error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn statement() {
+        let statements = parse_statements::<2>(STATEMENT).unwrap();
+        assert_eq!(statements.len(), 1);
+
+        let statement = &statements[0];
+        assert_eq!(statement.id, "STMT-2025-01");
+        assert_eq!(
+            statement.account.iban.as_deref(),
+            Some("DE89370400440532013000")
+        );
+        assert_eq!(statement.account.currency, Currency::EUR);
+        assert_eq!(statement.opening.kind, BalanceType::PreviouslyClosedBooked);
+        assert_eq!(statement.opening.amount, eur("1000.00"));
+        assert_eq!(statement.closing.amount, eur("3115.51"));
+        assert_eq!(statement.closing.date, date("2025-01-31"));
+        assert_eq!(statement.balances.len(), 3);
+        assert_eq!(statement.balances[2].kind, BalanceType::ClosingAvailable);
+    }
+
+    #[test]
+    fn entries() {
+        let statement = parse_statements::<2>(STATEMENT).unwrap().remove(0);
+        let [salary, invoices, pending] = statement.entries.as_slice() else {
+            panic!("expected 3 entries");
+        };
+
+        assert_eq!(salary.reference.as_deref(), Some("1"));
+        assert_eq!(salary.direction, Direction::Credit);
+        assert_eq!(salary.status, EntryStatus::Booked);
+        assert_eq!(salary.booking_date, Some(date("2025-01-27")));
+        assert_eq!(salary.value_date, Some(date("2025-01-28")));
+        assert_eq!(
+            salary.account_servicer_reference.as_deref(),
+            Some("BANK-0001")
+        );
+        assert_eq!(salary.end_to_end_ids, ["SALARY-2025-01"]);
+
+        assert_eq!(invoices.amount, eur("384.49"));
+        assert_eq!(invoices.signed_amount(), eur("-384.49"));
+        assert_eq!(invoices.booking_date, Some(date("2025-01-30")));
+        assert_eq!(invoices.end_to_end_ids, ["INV-42"]);
+        assert_eq!(
+            invoices.remittance_information,
+            ["Invoice 42", "Invoice 43"]
+        );
+
+        assert_eq!(pending.status, EntryStatus::Pending);
+        assert_eq!(pending.booking_date, None);
+    }
+
+    #[test]
+    fn older_version() {
+        let xml = STATEMENT
+            .replace("camt.053.001.08", "camt.053.001.02")
+            .replace("<Sts><Cd>BOOK</Cd></Sts>", "<Sts>BOOK</Sts>")
+            .replace("<Sts><Cd>PDNG</Cd></Sts>", "<Sts>PDNG</Sts>");
+        let statement = parse_statements::<2>(&xml).unwrap().remove(0);
+
+        assert_eq!(statement.entries[0].status, EntryStatus::Booked);
+        assert_eq!(statement.entries[2].status, EntryStatus::Pending);
+    }
+
+    #[test]
+    fn unbalanced() {
+        let xml = STATEMENT.replace(
+            "<Amt Ccy=\"EUR\">3115.51</Amt>",
+            "<Amt Ccy=\"EUR\">3115.50</Amt>",
+        );
+
+        assert_eq!(
+            parse_statements::<2>(&xml).unwrap_err().to_string(),
+            "statement 'STMT-2025-01' does not balance: closing balance 3115.50 EUR, opening \
+             balance plus entries 3115.51 EUR"
+        );
+    }
+
+    #[test]
+    fn debit_balance() {
+        let xml = STATEMENT
+            .replacen(
+                "<CdtDbtInd>CRDT</CdtDbtInd>",
+                "<CdtDbtInd>DBIT</CdtDbtInd>",
+                1,
+            )
+            .replace("3115.51", "1115.51");
+        let statement = parse_statements::<2>(&xml).unwrap().remove(0);
+
+        assert_eq!(statement.opening.amount, eur("-1000.00"));
+    }
+
+    #[test]
+    fn invalid_elements() {
+        assert_eq!(
+            element_error(&STATEMENT.replace("384.49", "384.495")),
+            FieldError {
+                line: 49,
+                field: "<Amt>".to_string(),
+                kind: ValueError::Amount(ParseAmountError::Scale {
+                    input: "384.495".to_string(),
+                    found: 3,
+                    expected: 2,
+                }),
+            }
+        );
+        assert_eq!(
+            element_error(&STATEMENT.replace(">384.49<", ">3.8449e2<")).kind,
+            ValueError::Amount(ParseAmountError::Invalid("3.8449e2".to_string()))
+        );
+        assert_eq!(
+            element_error(&STATEMENT.replace("Ccy=\"EUR\">384.49", "Ccy=\"CHF\">384.49")).kind,
+            ValueError::CurrencyMismatch {
+                expected: Currency::EUR,
+                found: Currency::CHF,
+            }
+        );
+        assert_eq!(
+            element_error(&STATEMENT.replacen("<Amt Ccy=\"EUR\">", "<Amt>", 1)).kind,
+            ValueError::Missing("Ccy")
+        );
+        assert_eq!(
+            element_error(&STATEMENT.replace("<CdtDbtInd>DBIT", "<CdtDbtInd>DEBIT")).kind,
+            ValueError::Invalid {
+                value: "DEBIT".to_string(),
+                expected: "CRDT or DBIT",
+            }
+        );
+        assert_eq!(
+            element_error(&STATEMENT.replace("<Dt>2025-01-27</Dt>", "<Dt>27.01.2025</Dt>")).kind,
+            ValueError::Invalid {
+                value: "27.01.2025".to_string(),
+                expected: "an ISO 8601 date",
+            }
+        );
+        assert_eq!(
+            element_error(&STATEMENT.replace(">2500.00<", ">-2500.00<")).kind,
+            ValueError::Invalid {
+                value: "-2500.00".to_string(),
+                expected: "an unsigned amount",
+            }
+        );
+    }
+
+    #[test]
+    fn missing_closing_balance() {
+        let xml = STATEMENT.replace("<Cd>CLBD</Cd>", "<Cd>ITBD</Cd>");
+
+        assert!(matches!(
+            parse_statements::<2>(&xml),
+            Err(CamtError::MissingBalance {
+                kind: "closing booked",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn not_camt053() {
+        let xml = "<Document><CstmrCdtTrfInitn/></Document>";
+
+        assert!(matches!(
+            parse_statements::<2>(xml),
+            Err(CamtError::NotCamt053(root)) if root == "Document"
+        ));
+        assert!(matches!(
+            parse_statements::<2>("<Document>"),
+            Err(CamtError::Xml(_))
+        ));
+    }
+
+    #[test]
+    fn file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("camt053.xml");
+        std::fs::write(&path, STATEMENT).unwrap();
+
+        let total: Amount<2> = read_statements::<2>(&path).unwrap()[0]
+            .entries
+            .iter()
+            .map(|entry| entry.signed_amount().amount())
+            .sum();
+
+        assert_eq!(total, "2015.51".parse().unwrap());
+    }
+}
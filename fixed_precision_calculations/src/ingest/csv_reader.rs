@@ -0,0 +1,412 @@
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+use crate::domain::value_type::currency::{Currency, ParseCurrencyError};
+use crate::domain::value_type::money::Money;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IngestError {
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[error("no column named '{0}' in the header")]
+    MissingColumn(String),
+    #[error(transparent)]
+    Row(#[from] RowError),
+}
+
+/// A field that could not be read, e.g. `line 12, column 'net': invalid decimal '12,30'`.
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("line {line}, column '{column}': {kind}")]
+pub struct RowError {
+    /// 1-based line in the file, counting the header.
+    pub line: u64,
+    pub column: String,
+    pub kind: FieldError,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum FieldError {
+    #[error(transparent)]
+    Amount(#[from] ParseAmountError),
+    #[error(transparent)]
+    Currency(#[from] ParseCurrencyError),
+    #[error("field is missing")]
+    Missing,
+}
+
+/// What to do with rows holding a field that cannot be parsed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ErrorPolicy {
+    /// Stop at the first invalid row, yielding its error.
+    #[default]
+    FailFast,
+    /// Drop invalid rows, only counting them.
+    Skip,
+    /// Drop invalid rows, keeping their errors for [`CsvAmountReader::errors`].
+    Collect,
+}
+
+/// The configured amount columns of one row, in the order they were added to the builder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmountRecord<const DECIMALS: usize> {
+    /// 1-based line in the file, counting the header.
+    pub line: u64,
+    pub amounts: Vec<Amount<DECIMALS>>,
+    /// Set when a currency column is configured.
+    pub currency: Option<Currency>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CsvAmountReaderBuilder {
+    columns: Vec<String>,
+    currency_column: Option<String>,
+    policy: ErrorPolicy,
+    delimiter: Option<u8>,
+}
+
+/// Streams amount columns out of a CSV file with a header row, one row at a time, so memory does not
+/// grow with the size of the file. Created with [`CsvAmountReaderBuilder`].
+///
+/// Amounts are read with [`Amount::from_str`](Amount#method.from_str).
+///
+/// ```
+/// use fixed_precision_calculations::domain::value_type::amount::Amount;
+/// use fixed_precision_calculations::ingest::csv_reader::{CsvAmountReaderBuilder, ErrorPolicy};
+///
+/// let csv = "date,net,vat\n2025-01-31,100.00,19.00\n2025-02-28,oops,1.00\n";
+///
+/// let mut reader = CsvAmountReaderBuilder::new()
+///     .column("net")
+///     .column("vat")
+///     .policy(ErrorPolicy::Collect)
+///     .from_reader::<_, 2>(csv.as_bytes())
+///     .unwrap();
+///
+/// let total: Amount<2> = reader.by_ref().map(|record| record.unwrap().amounts[0]).sum();
+///
+/// assert_eq!(total.to_string(), "100.00");
+/// assert_eq!(reader.errors()[0].line, 3);
+/// ```
+#[derive(Debug)]
+pub struct CsvAmountReader<R, const DECIMALS: usize> {
+    reader: csv::Reader<R>,
+    columns: Vec<(String, usize)>,
+    currency_column: Option<(String, usize)>,
+    policy: ErrorPolicy,
+    record: csv::StringRecord,
+    skipped: usize,
+    errors: Vec<RowError>,
+    done: bool,
+}
+
+impl CsvAmountReaderBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the column with this header name as an amount.
+    pub fn column(mut self, name: impl Into<String>) -> Self {
+        self.columns.push(name.into());
+        self
+    }
+
+    /// Reads the column with this header name as the ISO 4217 currency of the row's amounts.
+    pub fn currency_column(mut self, name: impl Into<String>) -> Self {
+        self.currency_column = Some(name.into());
+        self
+    }
+
+    pub fn policy(mut self, policy: ErrorPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Field delimiter, `,` by default.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    pub fn from_path<const DECIMALS: usize>(
+        self,
+        path: impl AsRef<Path>,
+    ) -> Result<CsvAmountReader<File, DECIMALS>, IngestError> {
+        self.from_reader(File::open(path).map_err(csv::Error::from)?)
+    }
+
+    /// Reads the header row and resolves the configured columns.
+    pub fn from_reader<R: Read, const DECIMALS: usize>(
+        self,
+        reader: R,
+    ) -> Result<CsvAmountReader<R, DECIMALS>, IngestError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter.unwrap_or(b','))
+            // Short rows are reported per field rather than failing the whole file.
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let headers = reader.headers()?.clone();
+        let resolve = |name: String| match headers.iter().position(|header| header == name) {
+            Some(index) => Ok((name, index)),
+            None => Err(IngestError::MissingColumn(name)),
+        };
+
+        Ok(CsvAmountReader {
+            columns: self
+                .columns
+                .into_iter()
+                .map(resolve)
+                .collect::<Result<_, _>>()?,
+            currency_column: self.currency_column.map(resolve).transpose()?,
+            reader,
+            policy: self.policy,
+            record: csv::StringRecord::new(),
+            skipped: 0,
+            errors: Vec::new(),
+            done: false,
+        })
+    }
+}
+
+impl<R: Read, const DECIMALS: usize> CsvAmountReader<R, DECIMALS> {
+    /// Names of the amount columns, in the order of [`AmountRecord::amounts`].
+    pub fn columns(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Number of rows dropped so far with [`ErrorPolicy::Skip`] or [`ErrorPolicy::Collect`].
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Errors collected so far with [`ErrorPolicy::Collect`], all of a row's invalid fields included.
+    pub fn errors(&self) -> &[RowError] {
+        &self.errors
+    }
+
+    /// Parses the current record, returning every invalid field.
+    fn parse_record(&self) -> Result<AmountRecord<DECIMALS>, Vec<RowError>> {
+        let line = self.record.position().map_or(0, |position| position.line());
+        let mut errors = Vec::new();
+
+        let amounts: Vec<Amount<DECIMALS>> = self
+            .columns
+            .iter()
+            .filter_map(|column| self.field(line, column, &mut errors))
+            .collect();
+        let currency = self
+            .currency_column
+            .as_ref()
+            .and_then(|column| self.field(line, column, &mut errors));
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(AmountRecord {
+            line,
+            amounts,
+            currency,
+        })
+    }
+
+    fn field<T>(
+        &self,
+        line: u64,
+        (name, index): &(String, usize),
+        errors: &mut Vec<RowError>,
+    ) -> Option<T>
+    where
+        T: FromStr,
+        T::Err: Into<FieldError>,
+    {
+        let value = match self.record.get(*index) {
+            Some(value) => value.parse().map_err(Into::into),
+            None => Err(FieldError::Missing),
+        };
+
+        value
+            .map_err(|kind| {
+                errors.push(RowError {
+                    line,
+                    column: name.clone(),
+                    kind,
+                })
+            })
+            .ok()
+    }
+}
+
+impl<R: Read, const DECIMALS: usize> Iterator for CsvAmountReader<R, DECIMALS> {
+    type Item = Result<AmountRecord<DECIMALS>, IngestError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            match self.reader.read_record(&mut self.record) {
+                Ok(true) => {}
+                Ok(false) => self.done = true,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err.into()));
+                }
+            }
+
+            if self.done {
+                break;
+            }
+
+            let errors = match self.parse_record() {
+                Ok(record) => return Some(Ok(record)),
+                Err(errors) => errors,
+            };
+
+            match self.policy {
+                ErrorPolicy::FailFast => {
+                    self.done = true;
+                    return errors.into_iter().next().map(|error| Err(error.into()));
+                }
+                ErrorPolicy::Skip => self.skipped += 1,
+                ErrorPolicy::Collect => {
+                    self.skipped += 1;
+                    self.errors.extend(errors);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<const DECIMALS: usize> AmountRecord<DECIMALS> {
+    /// The `index`-th amount together with the row's currency, `None` without a currency column.
+    pub fn money(&self, index: usize) -> Option<Money<DECIMALS>> {
+        Some(Money::new(*self.amounts.get(index)?, self.currency?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CsvAmountReaderBuilder, ErrorPolicy, FieldError, IngestError, RowError};
+    use crate::domain::value_type::amount::{Amount, ParseAmountError};
+    use crate::domain::value_type::currency::Currency;
+    use crate::domain::value_type::money::Money;
+
+    const CSV: &str = "\
+date,description,net,vat,currency
+2025-01-31,\"Rent, January\",1000.00,190.00,EUR
+2025-02-28,Invoice 42,12.345,2.35,EUR
+2025-03-31,Refund,-50.00,,CHF
+2025-04-30,Invoice 43,20.00,3.80,euro
+2025-05-31,Invoice 44,30.00
+";
+
+    fn reader(policy: ErrorPolicy) -> super::CsvAmountReader<&'static [u8], 2> {
+        CsvAmountReaderBuilder::new()
+            .column("net")
+            .column("vat")
+            .currency_column("currency")
+            .policy(policy)
+            .from_reader(CSV.as_bytes())
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_named_columns_with_currency() {
+        let record = reader(ErrorPolicy::FailFast).next().unwrap().unwrap();
+
+        assert_eq!(record.line, 2);
+        assert_eq!(
+            record.amounts,
+            [
+                Amount::new_scaled_i32(100_000),
+                Amount::new_scaled_i32(19_000)
+            ]
+        );
+        assert_eq!(
+            record.money(1),
+            Some(Money::new(Amount::new_scaled_i32(19_000), Currency::EUR))
+        );
+    }
+
+    #[test]
+    fn fail_fast() {
+        let results: Vec<_> = reader(ErrorPolicy::FailFast).collect();
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            &results[1],
+            Err(IngestError::Row(RowError {
+                line: 3,
+                column,
+                kind: FieldError::Amount(ParseAmountError::Scale { .. }),
+            })) if column == "net"
+        ));
+    }
+
+    #[test]
+    fn skip() {
+        let mut reader = reader(ErrorPolicy::Skip);
+        let lines: Vec<u64> = reader.by_ref().map(|record| record.unwrap().line).collect();
+
+        assert_eq!(lines, [2]);
+        assert_eq!(reader.skipped(), 4);
+        assert!(reader.errors().is_empty());
+    }
+
+    #[test]
+    fn collect_reports_every_invalid_field() {
+        let mut reader = reader(ErrorPolicy::Collect);
+        assert_eq!(reader.by_ref().count(), 1);
+
+        let errors: Vec<(u64, &str, String)> = reader
+            .errors()
+            .iter()
+            .map(|error| (error.line, error.column.as_str(), error.kind.to_string()))
+            .collect();
+
+        assert_eq!(
+            errors,
+            [
+                (
+                    3,
+                    "net",
+                    "'12.345' has 3 decimals, expected at most 2".to_string()
+                ),
+                (4, "vat", "invalid decimal ''".to_string()),
+                (
+                    5,
+                    "currency",
+                    "invalid currency code 'euro', expected 3 uppercase ASCII letters".to_string()
+                ),
+                (6, "vat", "field is missing".to_string()),
+                (6, "currency", "field is missing".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_column() {
+        let result = CsvAmountReaderBuilder::new()
+            .column("gross")
+            .from_reader::<_, 2>(CSV.as_bytes());
+
+        assert!(matches!(result, Err(IngestError::MissingColumn(name)) if name == "gross"));
+    }
+
+    #[test]
+    fn delimiter() {
+        let csv = "net;vat\n1.00;0.19\n";
+        let total: Amount<2> = CsvAmountReaderBuilder::new()
+            .column("vat")
+            .delimiter(b';')
+            .from_reader(csv.as_bytes())
+            .unwrap()
+            .map(|record| record.unwrap().amounts[0])
+            .sum();
+
+        assert_eq!(total, Amount::new_scaled_i32(19));
+    }
+}
@@ -0,0 +1,394 @@
+//! What the personal-finance importers ([OFX](super::ofx) and [QIF](super::qif)) have in common: the
+//! normalized [`ImportedTransaction`], locale-tolerant amount and date parsing, and deduplication by
+//! FITID.
+
+use crate::domain::transaction::Direction;
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+use crate::domain::value_type::currency::Currency;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// A transaction imported from a personal-finance file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedTransaction<const DECIMALS: usize> {
+    /// The account the transaction belongs to, e.g. OFX `<ACCTID>` or a QIF `!Account` name.
+    pub account: Option<String>,
+    /// OFX `<FITID>`, the bank's unique id of the transaction within the account.
+    pub fitid: Option<String>,
+    pub date: NaiveDate,
+    /// Negative for money leaving the account.
+    pub amount: Amount<DECIMALS>,
+    /// OFX `<CURDEF>`; QIF files do not name a currency.
+    pub currency: Option<Currency>,
+    pub payee: Option<String>,
+    pub memo: Option<String>,
+    pub check_number: Option<String>,
+}
+
+/// Which comes first in a date such as `01/02/2025`. Dates starting with a 4-digit year are always
+/// read year first.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum DateOrder {
+    /// `01/02/2025` is 2 January, as in US exports.
+    #[default]
+    MonthFirst,
+    /// `01/02/2025` is 1 February, as in most European exports.
+    DayFirst,
+}
+
+/// Which of `,` and `.` is the decimal separator in amounts such as `1,234`, when the locale of the
+/// file is known.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DecimalSeparator {
+    /// `1,234` is one thousand two hundred thirty-four, as in US exports.
+    Point,
+    /// `1,234` is one point two three four, as in most European exports.
+    Comma,
+}
+
+/// Remembers the FITIDs of the transactions seen so far, to drop those imported again, e.g. from
+/// overlapping downloads. FITIDs are only unique per account, so the key includes the account.
+#[derive(Debug, Clone, Default)]
+pub struct Deduplicator {
+    seen: HashSet<(Option<String>, String)>,
+}
+
+impl<const DECIMALS: usize> ImportedTransaction<DECIMALS> {
+    pub fn direction(&self) -> Direction {
+        match self.amount.is_negative() {
+            true => Direction::Debit,
+            false => Direction::Credit,
+        }
+    }
+}
+
+impl Deduplicator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the transaction's FITID, returning whether it is new. Transactions without a FITID
+    /// cannot be recognised and are always new.
+    pub fn insert<const DECIMALS: usize>(
+        &mut self,
+        transaction: &ImportedTransaction<DECIMALS>,
+    ) -> bool {
+        match &transaction.fitid {
+            Some(fitid) => self
+                .seen
+                .insert((transaction.account.clone(), fitid.clone())),
+            None => true,
+        }
+    }
+
+    /// Keeps the transactions not seen before, in order.
+    pub fn retain_new<const DECIMALS: usize>(
+        &mut self,
+        transactions: impl IntoIterator<Item = ImportedTransaction<DECIMALS>>,
+    ) -> Vec<ImportedTransaction<DECIMALS>> {
+        transactions
+            .into_iter()
+            .filter(|transaction| self.insert(transaction))
+            .collect()
+    }
+
+    /// Number of distinct FITIDs seen.
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+}
+
+/// Reads a text file, falling back to Windows-1252 for files that are not UTF-8, as bank exports
+/// often use it, e.g. for `€` (0x80) or `’` (0x92).
+pub(crate) fn read_text(path: impl AsRef<Path>) -> std::io::Result<String> {
+    Ok(match String::from_utf8(std::fs::read(path)?) {
+        Ok(text) => text,
+        Err(error) => encoding_rs::WINDOWS_1252
+            .decode_without_bom_handling(error.as_bytes())
+            .0
+            .into_owned(),
+    })
+}
+
+/// Parses an amount written in any common locale: `1,234.56`, `1.234,56`, `1'234.56`, `1 234,56`,
+/// `-12.34`, `12.34-` or `(12.34)`.
+///
+/// With a `separator` hint only that character is the decimal separator. Without one, the last of
+/// `,` and `.` is, if both are present, and a repeated one is grouping. A single separator followed
+/// by exactly three digits, as in `1,234`, could be either and is rejected unless the integer part
+/// is `0`.
+pub fn parse_amount<const DECIMALS: usize>(
+    text: &str,
+    separator: Option<DecimalSeparator>,
+) -> Result<Amount<DECIMALS>, ParseAmountError> {
+    let invalid = || ParseAmountError::Invalid(text.to_string());
+    let trimmed = text.trim();
+    let (negative, unsigned) = if let Some(inner) = trimmed
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+    {
+        (true, inner)
+    } else if let Some(unsigned) = trimmed.strip_prefix('-') {
+        (true, unsigned)
+    } else if let Some(unsigned) = trimmed.strip_suffix('-') {
+        (true, unsigned)
+    } else {
+        (false, trimmed.strip_prefix('+').unwrap_or(trimmed))
+    };
+
+    let digits: String = unsigned
+        .chars()
+        .filter(|char| !matches!(char, ' ' | '\u{a0}' | '\'' | '\u{2019}'))
+        .collect();
+    let decimal = match separator {
+        Some(DecimalSeparator::Point) => digits.rfind('.').map(|position| (position, '.')),
+        Some(DecimalSeparator::Comma) => digits.rfind(',').map(|position| (position, ',')),
+        None => decimal_separator(&digits).ok_or_else(invalid)?,
+    };
+    let integer = &digits[..decimal.map_or(digits.len(), |(position, _)| position)];
+    if integer.contains([',', '.']) && !grouped_by_three(integer) {
+        return Err(invalid());
+    }
+
+    let mut normalized = String::with_capacity(digits.len() + 1);
+    if negative {
+        normalized.push('-');
+    }
+    for (index, char) in digits.char_indices() {
+        match char {
+            '0'..='9' => normalized.push(char),
+            _ if decimal.is_some_and(|(position, _)| position == index) => normalized.push('.'),
+            // Grouping separators only come before, and differ from, the decimal separator.
+            ',' | '.'
+                if decimal
+                    .is_none_or(|(position, separator)| index < position && char != separator) => {}
+            _ => return Err(invalid()),
+        }
+    }
+
+    normalized.parse().map_err(|error| match error {
+        ParseAmountError::Scale {
+            found, expected, ..
+        } => ParseAmountError::Scale {
+            input: text.to_string(),
+            found,
+            expected,
+        },
+        ParseAmountError::Invalid(_) => invalid(),
+    })
+}
+
+/// The position and character of the decimal separator in `digits`, if any, or `None` if it cannot
+/// be told from grouping.
+fn decimal_separator(digits: &str) -> Option<Option<(usize, char)>> {
+    let Some(position) = digits.rfind([',', '.']) else {
+        return Some(None);
+    };
+    let separator = char::from(digits.as_bytes()[position]);
+    let other = if separator == ',' { '.' } else { ',' };
+
+    if digits.contains(other) {
+        return Some(Some((position, separator)));
+    }
+    if digits.matches(separator).count() > 1 {
+        return Some(None);
+    }
+
+    let integer = &digits[..position];
+    let ambiguous = digits.len() - position - 1 == 3 && !integer.is_empty() && integer != "0";
+    (!ambiguous).then_some(Some((position, separator)))
+}
+
+/// Whether the separators split `integer` into groups of three digits after the first.
+fn grouped_by_three(integer: &str) -> bool {
+    let mut groups = integer.split([',', '.']);
+    groups
+        .next()
+        .is_some_and(|first| (1..=3).contains(&first.len()))
+        && groups.all(|group| group.len() == 3)
+}
+
+/// Parses a date written in any common locale: `01/31/2025`, `1/31'25`, `31.01.2025`,
+/// `2025-01-31` or `20250131`. Two-digit years from 69 are in the 1900s, the others in the 2000s.
+/// Quicken marks years of the 2000s with `'` and pads them with a space, as in `1/ 5' 5`, so one- or
+/// two-digit years after `'` are always in the 2000s.
+pub fn parse_date(text: &str, order: DateOrder) -> Option<NaiveDate> {
+    let text: String = text.chars().filter(|char| !char.is_whitespace()).collect();
+    if text.len() == 8 && text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return NaiveDate::parse_from_str(&text, "%Y%m%d").ok();
+    }
+
+    const SEPARATORS: [char; 4] = ['/', '-', '.', '\''];
+    let quicken = text
+        .rfind(SEPARATORS)
+        .is_some_and(|position| text[position..].starts_with('\''));
+    let parts: Vec<&str> = text.split(SEPARATORS).collect();
+    let [first, second, third] = parts.as_slice() else {
+        return None;
+    };
+    let (year, month, day) = match (first.len(), order) {
+        (4, _) => (first, second, third),
+        (_, DateOrder::MonthFirst) => (third, first, second),
+        (_, DateOrder::DayFirst) => (third, second, first),
+    };
+
+    let year: i32 = match (year.len(), year.parse().ok()?) {
+        (1 | 2, year) if quicken => 2000 + year,
+        (2, year) if year < 69 => 2000 + year,
+        (2, year) => 1900 + year,
+        (4, year) => year,
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DateOrder, DecimalSeparator, Deduplicator, ImportedTransaction, parse_amount, parse_date,
+    };
+    use crate::domain::value_type::amount::{Amount, ParseAmountError};
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1234.56", "1234.56")]
+    #[case("1,234.56", "1234.56")]
+    #[case("1.234,56", "1234.56")]
+    #[case("1'234.56", "1234.56")]
+    #[case("1 234,5", "1234.50")]
+    #[case("1,234,567.8", "1234567.80")]
+    #[case("1.234.567", "1234567.00")]
+    #[case("0,12", "0.12")]
+    #[case(" -12.34 ", "-12.34")]
+    #[case("12.34-", "-12.34")]
+    #[case("(12.34)", "-12.34")]
+    #[case("+5", "5.00")]
+    fn amounts(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(parse_amount::<2>(text, None).unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("1,234", DecimalSeparator::Point, "1234.000")]
+    #[case("1,234", DecimalSeparator::Comma, "1.234")]
+    #[case("1.234", DecimalSeparator::Point, "1.234")]
+    #[case("1.234", DecimalSeparator::Comma, "1234.000")]
+    #[case("1.234,5", DecimalSeparator::Comma, "1234.500")]
+    #[case("12", DecimalSeparator::Comma, "12.000")]
+    #[case("0,123", DecimalSeparator::Comma, "0.123")]
+    fn amounts_with_separator(
+        #[case] text: &str,
+        #[case] separator: DecimalSeparator,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            parse_amount::<3>(text, Some(separator))
+                .unwrap()
+                .to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("12.34.5")]
+    #[case("1.234,56,7")]
+    #[case("12,34 EUR")]
+    #[case("1,23,456.78")]
+    #[case("-")]
+    #[case("")]
+    fn invalid_amounts(#[case] text: &str) {
+        assert_eq!(
+            parse_amount::<2>(text, None),
+            Err(ParseAmountError::Invalid(text.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("1,234", None)]
+    #[case("-1.234", None)]
+    #[case("1,234.56", Some(DecimalSeparator::Comma))]
+    #[case("1.234,56", Some(DecimalSeparator::Point))]
+    #[case("1.234.567", Some(DecimalSeparator::Point))]
+    #[case("12,34", Some(DecimalSeparator::Point))]
+    fn ambiguous_or_mismatched_amounts(
+        #[case] text: &str,
+        #[case] separator: Option<DecimalSeparator>,
+    ) {
+        assert_eq!(
+            parse_amount::<3>(text, separator),
+            Err(ParseAmountError::Invalid(text.to_string()))
+        );
+    }
+
+    #[test]
+    fn amount_scale() {
+        assert_eq!(
+            parse_amount::<2>("0,125", None),
+            Err(ParseAmountError::Scale {
+                input: "0,125".to_string(),
+                found: 3,
+                expected: 2,
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("01/31/2025", DateOrder::MonthFirst, "2025-01-31")]
+    #[case("1/31'25", DateOrder::MonthFirst, "2025-01-31")]
+    #[case(" 1/ 5/99", DateOrder::MonthFirst, "1999-01-05")]
+    #[case("1/ 5' 5", DateOrder::MonthFirst, "2005-01-05")]
+    #[case("31.12' 9", DateOrder::DayFirst, "2009-12-31")]
+    #[case("01/02/2025", DateOrder::DayFirst, "2025-02-01")]
+    #[case("31.01.2025", DateOrder::DayFirst, "2025-01-31")]
+    #[case("2025-01-31", DateOrder::DayFirst, "2025-01-31")]
+    #[case("20250131", DateOrder::MonthFirst, "2025-01-31")]
+    fn dates(#[case] text: &str, #[case] order: DateOrder, #[case] expected: &str) {
+        assert_eq!(parse_date(text, order), expected.parse().ok());
+    }
+
+    #[rstest]
+    #[case("31/01/2025", DateOrder::MonthFirst)]
+    #[case("2025-02-30", DateOrder::MonthFirst)]
+    #[case("31 Jan 2025", DateOrder::DayFirst)]
+    #[case("1/31/025", DateOrder::MonthFirst)]
+    #[case("1/5/5", DateOrder::MonthFirst)]
+    fn invalid_dates(#[case] text: &str, #[case] order: DateOrder) {
+        assert_eq!(parse_date(text, order), None);
+    }
+
+    #[test]
+    fn deduplicates_by_account_and_fitid() {
+        let transaction = |account: &str, fitid: Option<&str>| ImportedTransaction::<2> {
+            account: Some(account.to_string()),
+            fitid: fitid.map(str::to_string),
+            date: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            amount: Amount::new_scaled_i32(-1234),
+            currency: None,
+            payee: None,
+            memo: None,
+            check_number: None,
+        };
+        let mut deduplicator = Deduplicator::new();
+
+        let first = deduplicator.retain_new([
+            transaction("checking", Some("1")),
+            transaction("checking", Some("2")),
+            transaction("checking", Some("1")),
+        ]);
+        let second = deduplicator.retain_new([
+            transaction("checking", Some("2")),
+            transaction("savings", Some("2")),
+            transaction("checking", None),
+            transaction("checking", None),
+        ]);
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 3);
+        assert_eq!(deduplicator.len(), 3);
+    }
+}
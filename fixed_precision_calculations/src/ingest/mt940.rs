@@ -0,0 +1,632 @@
+//! SWIFT MT940 (customer statement) parser for the `:20:`, `:25:`, `:28C:`, `:60F:`, `:61:`, `:86:`
+//! and `:62F:` fields.
+//!
+//! The parser is tolerant of what banks actually send: CRLF line endings, blank lines, SWIFT
+//! `{1:...}` envelopes, intermediate `:60M:`/`:62M:` balances and fields it does not know, which are
+//! skipped. Amounts use a decimal comma, e.g. `1234,56`, and are otherwise read like
+//! [`Amount::from_str`](Amount#method.from_str). Each statement is checked to balance, see
+//! [`Statement::validate`].
+
+use crate::domain::transaction::Direction;
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::money::Money;
+use crate::ingest::import::read_text;
+use crate::ingest::statement::{
+    FieldError, Unbalanced, ValueError, check_continuity, signed_amount,
+};
+use chrono::{Datelike, NaiveDate};
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Mt940Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Field(#[from] FieldError),
+    #[error("line {line}: expected a field such as ':20:', found '{text}'")]
+    UnexpectedLine { line: u64, text: String },
+    #[error("line {line}: statement '{statement}' has no :{tag}: field")]
+    MissingField {
+        /// The line ending the statement.
+        line: u64,
+        statement: String,
+        tag: &'static str,
+    },
+    #[error(transparent)]
+    Unbalanced(#[from] Unbalanced),
+}
+
+/// One statement of an MT940 file, from its `:20:` field to its `:62F:` field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement<const DECIMALS: usize> {
+    /// `:20:` transaction reference number.
+    pub reference: String,
+    /// `:25:` account identification, e.g. `10020030/1234567` or an IBAN.
+    pub account: String,
+    /// `:28C:` statement and sequence number, e.g. `00001/001`.
+    pub number: Option<String>,
+    /// `:60F:`, or `:60M:` for a statement continuing an earlier one.
+    pub opening: Balance<DECIMALS>,
+    /// `:62F:`, or `:62M:` for a statement continued by a later one.
+    pub closing: Balance<DECIMALS>,
+    pub transactions: Vec<TransactionRecord<DECIMALS>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Balance<const DECIMALS: usize> {
+    /// Negative for a debit balance.
+    pub amount: Money<DECIMALS>,
+    pub date: NaiveDate,
+    /// Set for an intermediate (`M`) rather than final (`F`) balance.
+    pub intermediate: bool,
+}
+
+/// A `:61:` statement line together with the `:86:` information following it.
+///
+/// `amount` is always the non-negative magnitude; the sign follows from `direction`, see
+/// [`TransactionRecord::signed_amount`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionRecord<const DECIMALS: usize> {
+    /// 1-based line of the `:61:` field in the file.
+    pub line: u64,
+    pub value_date: NaiveDate,
+    /// The optional entry date, in the year closest to the value date.
+    pub booking_date: Option<NaiveDate>,
+    pub amount: Money<DECIMALS>,
+    pub direction: Direction,
+    /// Set for the `RC` and `RD` marks; a reversed credit is a debit and vice versa.
+    pub reversal: bool,
+    /// Identification code, e.g. `NTRF` or `NMSC`.
+    pub transaction_type: String,
+    /// Reference for the account owner, `NONREF` when there is none.
+    pub customer_reference: String,
+    /// Reference of the account servicing institution, after `//`.
+    pub bank_reference: Option<String>,
+    /// The optional second line of the `:61:` field.
+    pub supplementary_details: Option<String>,
+    /// `:86:` information to the account owner, lines joined with `\n`.
+    pub information: Option<String>,
+}
+
+/// Reads every statement of the MT940 file at `path`.
+pub fn read_statements<const DECIMALS: usize>(
+    path: impl AsRef<Path>,
+) -> Result<Vec<Statement<DECIMALS>>, Mt940Error> {
+    // MT940 is restricted to the SWIFT character set, but some banks send Windows-1252 umlauts.
+    parse_statements(&read_text(path)?)
+}
+
+/// Parses every statement of an MT940 file, failing on the first malformed field or on a
+/// statement that does not balance.
+///
+/// ```
+/// use fixed_precision_calculations::ingest::mt940::parse_statements;
+///
+/// let mt940 = "\
+/// :20:STARTUMS
+/// :25:10020030/1234567
+/// :28C:1/1
+/// :60F:C250101EUR100,00
+/// :61:2501150115D12,34NTRFNONREF//B-0001
+/// :86:Invoice 42
+/// :62F:C250131EUR87,66
+/// -";
+///
+/// let statements = parse_statements::<2>(mt940).unwrap();
+///
+/// assert_eq!(statements[0].transactions[0].signed_amount().to_string(), "-12.34 EUR");
+/// ```
+pub fn parse_statements<const DECIMALS: usize>(
+    text: &str,
+) -> Result<Vec<Statement<DECIMALS>>, Mt940Error> {
+    let mut statements = Vec::new();
+    let mut fields: Vec<Field> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let number = index as u64 + 1;
+        let line = line.trim_end();
+
+        if line.is_empty() || line.starts_with('{') {
+            continue;
+        }
+        if line == "-" || line == "-}" {
+            if !fields.is_empty() {
+                statements.push(statement(&fields, number)?);
+                fields.clear();
+            }
+            continue;
+        }
+
+        match split_tag(line) {
+            Some(("20", _)) if !fields.is_empty() => {
+                statements.push(statement(&fields, number)?);
+                fields.clear();
+            }
+            _ => {}
+        }
+        match (split_tag(line), fields.last_mut()) {
+            (Some((tag, value)), _) => fields.push(Field {
+                line: number,
+                tag,
+                lines: vec![value],
+            }),
+            (None, Some(field)) => field.lines.push(line),
+            (None, None) => {
+                return Err(Mt940Error::UnexpectedLine {
+                    line: number,
+                    text: line.to_string(),
+                });
+            }
+        }
+    }
+
+    if !fields.is_empty() {
+        let end = text.lines().count() as u64;
+        statements.push(statement(&fields, end)?);
+    }
+
+    for statement in &statements {
+        statement.validate()?;
+    }
+    Ok(statements)
+}
+
+impl<const DECIMALS: usize> Statement<DECIMALS> {
+    /// Checks that the opening balance plus the transactions equals the closing balance.
+    pub fn validate(&self) -> Result<(), Unbalanced> {
+        check_continuity(
+            &self.reference,
+            self.opening.amount,
+            self.transactions
+                .iter()
+                .map(|transaction| transaction.signed_amount().amount()),
+            self.closing.amount,
+        )
+    }
+}
+
+impl<const DECIMALS: usize> TransactionRecord<DECIMALS> {
+    pub fn signed_amount(&self) -> Money<DECIMALS> {
+        signed_amount(self.amount, self.direction)
+    }
+}
+
+/// A field with its continuation lines.
+struct Field<'a> {
+    line: u64,
+    tag: &'a str,
+    lines: Vec<&'a str>,
+}
+
+impl Field<'_> {
+    fn value(&self) -> &str {
+        self.lines[0].trim()
+    }
+
+    fn error(&self, kind: impl Into<ValueError>) -> Mt940Error {
+        FieldError::new(self.line, format!(":{}:", self.tag), kind).into()
+    }
+
+    fn invalid(&self, expected: &'static str) -> Mt940Error {
+        self.error(ValueError::invalid(self.lines.join("\n"), expected))
+    }
+}
+
+/// Splits `:61:value` into `61` and `value`, for tags of two digits and an optional letter.
+fn split_tag(line: &str) -> Option<(&str, &str)> {
+    let (tag, value) = line.strip_prefix(':')?.split_once(':')?;
+    let bytes = tag.as_bytes();
+    let valid = matches!(bytes.len(), 2 | 3)
+        && bytes[..2].iter().all(u8::is_ascii_digit)
+        && bytes[2..].iter().all(u8::is_ascii_uppercase);
+
+    valid.then_some((tag, value))
+}
+
+/// Builds the statement out of its fields, `end` being the line that ends it.
+fn statement<const DECIMALS: usize>(
+    fields: &[Field],
+    end: u64,
+) -> Result<Statement<DECIMALS>, Mt940Error> {
+    let find = |tags: &[&str]| fields.iter().find(|field| tags.contains(&field.tag));
+    let reference = find(&["20"]).map_or_else(String::new, |field| field.value().to_string());
+    let required = |tags: &[&str], tag: &'static str| {
+        find(tags).ok_or_else(|| Mt940Error::MissingField {
+            line: end,
+            statement: reference.clone(),
+            tag,
+        })
+    };
+
+    required(&["20"], "20")?;
+    let account = required(&["25", "25P"], "25")?.value().to_string();
+    let opening = balance(required(&["60F", "60M"], "60F")?, None)?;
+    let currency = opening.amount.currency();
+    let closing = balance(required(&["62F", "62M"], "62F")?, Some(currency))?;
+
+    let mut transactions: Vec<TransactionRecord<DECIMALS>> = Vec::new();
+    let mut previous = "";
+    for field in fields {
+        match field.tag {
+            "61" => transactions.push(statement_line(field, currency)?),
+            // Information after a balance concerns the statement rather than a transaction.
+            "86" if previous == "61" => {
+                if let Some(transaction) = transactions.last_mut() {
+                    transaction.information = Some(
+                        field
+                            .lines
+                            .iter()
+                            .map(|line| line.trim())
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    );
+                }
+            }
+            _ => {}
+        }
+        previous = field.tag;
+    }
+
+    Ok(Statement {
+        number: find(&["28C", "28"]).map(|field| field.value().to_string()),
+        reference,
+        account,
+        opening,
+        closing,
+        transactions,
+    })
+}
+
+/// `C250131EUR1234,56`: mark, date, currency and amount. `currency` is that of the opening
+/// balance, which every other amount must match.
+fn balance<const DECIMALS: usize>(
+    field: &Field,
+    currency: Option<Currency>,
+) -> Result<Balance<DECIMALS>, Mt940Error> {
+    let value = field.value();
+    let (mark, rest) = value
+        .split_at_checked(1)
+        .ok_or_else(|| field.invalid(BALANCE))?;
+    let (date, rest) = rest
+        .split_at_checked(6)
+        .ok_or_else(|| field.invalid(BALANCE))?;
+    let (found, amount) = rest
+        .split_at_checked(3)
+        .ok_or_else(|| field.invalid(BALANCE))?;
+
+    let date = parse_date(date).ok_or_else(|| field.invalid(BALANCE))?;
+    let found: Currency = found
+        .parse()
+        .map_err(|error| field.error(ValueError::Currency(error)))?;
+    let amount = money(field, amount, found, currency.unwrap_or(found))?;
+
+    Ok(Balance {
+        amount: match mark {
+            "C" => amount,
+            "D" => -amount,
+            _ => return Err(field.invalid(BALANCE)),
+        },
+        date,
+        intermediate: field.tag.ends_with('M'),
+    })
+}
+
+const BALANCE: &str = "a balance such as 'C250131EUR1234,56'";
+const STATEMENT_LINE: &str = "a statement line such as '2501310131D12,34NTRFNONREF//B-1'";
+
+/// `2501310131D12,34NTRFNONREF//B-1`: value date, optional entry date, mark, optional funds code,
+/// amount, transaction type, customer reference and optional bank reference.
+fn statement_line<const DECIMALS: usize>(
+    field: &Field,
+    currency: Currency,
+) -> Result<TransactionRecord<DECIMALS>, Mt940Error> {
+    let invalid = || field.invalid(STATEMENT_LINE);
+    let value = field.value();
+
+    let (value_date, mut rest) = value.split_at_checked(6).ok_or_else(invalid)?;
+    let value_date = parse_date(value_date).ok_or_else(invalid)?;
+
+    let mut booking_date = None;
+    if let Some((month_day, after)) = rest.split_at_checked(4)
+        && month_day.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        booking_date = Some(entry_date(value_date, month_day).ok_or_else(invalid)?);
+        rest = after;
+    }
+
+    let (direction, reversal, mut rest) = if let Some(rest) = rest.strip_prefix("RC") {
+        (Direction::Debit, true, rest)
+    } else if let Some(rest) = rest.strip_prefix("RD") {
+        (Direction::Credit, true, rest)
+    } else if let Some(rest) = rest.strip_prefix('C') {
+        (Direction::Credit, false, rest)
+    } else if let Some(rest) = rest.strip_prefix('D') {
+        (Direction::Debit, false, rest)
+    } else {
+        return Err(invalid());
+    };
+
+    // The funds code is the third letter of the currency code.
+    if rest.starts_with(|char: char| char.is_ascii_alphabetic()) {
+        rest = &rest[1..];
+    }
+
+    let amount_len = rest
+        .find(|char: char| !char.is_ascii_digit() && char != ',')
+        .ok_or_else(invalid)?;
+    let (amount, rest) = rest.split_at(amount_len);
+    let amount = money(field, amount, currency, currency)?;
+
+    // `N` followed by a code such as `TRF`, or `F`/`S` followed by a message type.
+    let (transaction_type, references) = rest.split_at_checked(4).ok_or_else(invalid)?;
+    if !transaction_type.starts_with(['N', 'F', 'S'])
+        || !transaction_type
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric())
+    {
+        return Err(invalid());
+    }
+    let (customer_reference, bank_reference) = match references.split_once("//") {
+        Some((customer, bank)) => (customer, Some(bank.to_string())),
+        None => (references, None),
+    };
+
+    Ok(TransactionRecord {
+        line: field.line,
+        value_date,
+        booking_date,
+        amount,
+        direction,
+        reversal,
+        transaction_type: transaction_type.to_string(),
+        customer_reference: customer_reference.to_string(),
+        bank_reference,
+        supplementary_details: field.lines.get(1).map(|line| line.trim().to_string()),
+        information: None,
+    })
+}
+
+/// Parses a comma-decimal amount such as `1234,56` or `1234,` in currency `found`, which must be
+/// `expected`.
+fn money<const DECIMALS: usize>(
+    field: &Field,
+    amount: &str,
+    found: Currency,
+    expected: Currency,
+) -> Result<Money<DECIMALS>, Mt940Error> {
+    if found != expected {
+        return Err(field.error(ValueError::CurrencyMismatch { expected, found }));
+    }
+
+    let (integer, fraction) = amount.split_once(',').unwrap_or((amount, ""));
+    let digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if integer.is_empty() || !digits(integer) || !digits(fraction) {
+        return Err(field.error(ValueError::invalid(
+            amount,
+            "an amount with a decimal comma such as '1234,56'",
+        )));
+    }
+
+    let decimal = match fraction {
+        "" => integer.to_string(),
+        fraction => format!("{integer}.{fraction}"),
+    };
+    let amount: Amount<DECIMALS> = decimal
+        .parse()
+        .map_err(|error| field.error(ValueError::Amount(error)))?;
+
+    Ok(Money::new(amount, found))
+}
+
+/// A `YYMMDD` date.
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    if !date.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    NaiveDate::parse_from_str(date, "%y%m%d").ok()
+}
+
+/// An `MMDD` entry date in the year closest to `value_date`, so a booking on 31 December with a
+/// value date in January falls into the previous year.
+fn entry_date(value_date: NaiveDate, month_day: &str) -> Option<NaiveDate> {
+    let month = month_day[..2].parse().ok()?;
+    let day = month_day[2..].parse().ok()?;
+    let year = match (value_date.month(), month) {
+        (1, 12) => value_date.year() - 1,
+        (12, 1) => value_date.year() + 1,
+        _ => value_date.year(),
+    };
+
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldError, Mt940Error, ValueError, parse_statements, read_statements};
+    use crate::domain::transaction::Direction;
+    use crate::domain::value_type::amount::ParseAmountError;
+    use crate::domain::value_type::currency::Currency;
+    use crate::ingest::statement::Unbalanced;
+    use crate::ingest::test_support::{date, eur};
+    use rstest::rstest;
+
+    const MT940: &str = "\
+{1:F01BANKDEFFAXXX0000000000}{2:O9400000000000BANKDEFFAXXX00000000000000000000N}{4:\r
+:20:STMT-2025-01\r
+:25:10020030/1234567\r
+:28C:00001/001\r
+:60F:C241231EUR1000,00\r
+:61:2501280127C2500,00NTRFSALARY-2025-01//BANK-0001\r
+SALARY JANUARY\r
+:86:166?00GUTSCHRIFT?20Salary January\r
+?21ACME GmbH\r
+:61:250130D384,49NDDTNONREF\r
+:86:Invoice 42\r
+:61:250131RD0,5NMSCNONREF\r
+:62F:C250131EUR3116,01\r
+:86:Statement information\r
+-}\r
+:20:STMT-2025-02\r
+:25:10020030/1234567\r
+:28C:00002/001\r
+:60F:C250131EUR3116,\r
+:62F:C250228EUR3116,00\r
+";
+
+    fn field_error(mt940: &str) -> FieldError {
+        match parse_statements::<2>(mt940) {
+            Err(Mt940Error::Field(error)) => error,
+            result => panic!("expected a field error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn statements() {
+        let statements = parse_statements::<2>(MT940).unwrap();
+        assert_eq!(statements.len(), 2);
+
+        let january = &statements[0];
+        assert_eq!(january.reference, "STMT-2025-01");
+        assert_eq!(january.account, "10020030/1234567");
+        assert_eq!(january.number.as_deref(), Some("00001/001"));
+        assert_eq!(january.opening.amount, eur("1000.00"));
+        assert_eq!(january.opening.date, date("2024-12-31"));
+        assert_eq!(january.closing.amount, eur("3116.01"));
+        assert!(!january.closing.intermediate);
+        assert_eq!(january.transactions.len(), 3);
+
+        assert_eq!(statements[1].closing.amount, eur("3116.00"));
+        assert!(statements[1].transactions.is_empty());
+    }
+
+    #[test]
+    fn transactions() {
+        let statement = parse_statements::<2>(MT940).unwrap().remove(0);
+        let [salary, invoice, reversal] = statement.transactions.as_slice() else {
+            panic!("expected 3 transactions");
+        };
+
+        assert_eq!(salary.line, 6);
+        assert_eq!(salary.value_date, date("2025-01-28"));
+        assert_eq!(salary.booking_date, Some(date("2025-01-27")));
+        assert_eq!(salary.signed_amount(), eur("2500.00"));
+        assert_eq!(salary.transaction_type, "NTRF");
+        assert_eq!(salary.customer_reference, "SALARY-2025-01");
+        assert_eq!(salary.bank_reference.as_deref(), Some("BANK-0001"));
+        assert_eq!(
+            salary.supplementary_details.as_deref(),
+            Some("SALARY JANUARY")
+        );
+        assert_eq!(
+            salary.information.as_deref(),
+            Some("166?00GUTSCHRIFT?20Salary January\n?21ACME GmbH")
+        );
+
+        assert_eq!(invoice.booking_date, None);
+        assert_eq!(invoice.signed_amount(), eur("-384.49"));
+        assert_eq!(invoice.customer_reference, "NONREF");
+        assert_eq!(invoice.bank_reference, None);
+        assert_eq!(invoice.information.as_deref(), Some("Invoice 42"));
+
+        assert_eq!(reversal.direction, Direction::Credit);
+        assert!(reversal.reversal);
+        assert_eq!(reversal.amount, eur("0.50"));
+        assert_eq!(reversal.information, None);
+    }
+
+    #[rstest]
+    #[case(":61:2501020101", "2025-01-01")]
+    #[case(":61:2501021231", "2024-12-31")]
+    #[case(":61:2412310101", "2025-01-01")]
+    fn entry_date_year(#[case] prefix: &str, #[case] expected: &str) {
+        let mt940 =
+            format!(":20:1\n:25:1\n:60F:C250101EUR0,\n{prefix}C1,NTRFNONREF\n:62F:C250101EUR1,\n-");
+        let statement = parse_statements::<2>(&mt940).unwrap().remove(0);
+
+        assert_eq!(statement.transactions[0].booking_date, Some(date(expected)));
+    }
+
+    #[test]
+    fn funds_code_and_debit_balance() {
+        let mt940 =
+            ":20:1\n:25:1\n:60F:D250101EUR10,\n:61:250102CR12,34NTRFNONREF\n:62F:C250102EUR2,34";
+        let statement = parse_statements::<2>(mt940).unwrap().remove(0);
+
+        assert_eq!(statement.opening.amount, eur("-10.00"));
+        assert_eq!(statement.transactions[0].amount, eur("12.34"));
+    }
+
+    #[test]
+    fn unbalanced() {
+        let mt940 = MT940.replace(":62F:C250131EUR3116,01", ":62F:C250131EUR3116,02");
+
+        assert!(matches!(
+            parse_statements::<2>(&mt940),
+            Err(Mt940Error::Unbalanced(error)) if error == Unbalanced::Mismatch {
+                statement: "STMT-2025-01".to_string(),
+                closing: "3116.02 EUR".to_string(),
+                computed: "3116.01 EUR".to_string(),
+            }
+        ));
+    }
+
+    #[test]
+    fn malformed_fields() {
+        assert_eq!(
+            field_error(&MT940.replace("D384,49", "D384,495")),
+            FieldError {
+                line: 10,
+                field: ":61:".to_string(),
+                kind: ValueError::Amount(ParseAmountError::Scale {
+                    input: "384.495".to_string(),
+                    found: 3,
+                    expected: 2,
+                }),
+            }
+        );
+        assert_eq!(
+            field_error(&MT940.replace("D384,49", "D384.49")).to_string(),
+            "line 10, :61:: invalid value '250130D384.49NDDTNONREF', expected a statement line \
+             such as '2501310131D12,34NTRFNONREF//B-1'"
+        );
+        assert_eq!(
+            field_error(&MT940.replace("C250131EUR3116,01", "C250131CHF3116,01")).kind,
+            ValueError::CurrencyMismatch {
+                expected: Currency::EUR,
+                found: Currency::CHF,
+            }
+        );
+        assert_eq!(
+            field_error(&MT940.replace(":60F:C241231", ":60F:C241331")).line,
+            5
+        );
+        assert_eq!(
+            field_error(&MT940.replace(":61:250130D", ":61:250130X")).line,
+            10
+        );
+    }
+
+    #[test]
+    fn missing_fields() {
+        assert!(matches!(
+            parse_statements::<2>(&MT940.replace(":62F:C250131EUR3116,01\r\n", "")),
+            Err(Mt940Error::MissingField { line: 14, statement, tag: "62F" })
+                if statement == "STMT-2025-01"
+        ));
+        assert!(matches!(
+            parse_statements::<2>("garbage\n:20:1"),
+            Err(Mt940Error::UnexpectedLine { line: 1, text }) if text == "garbage"
+        ));
+    }
+
+    #[test]
+    fn file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("statement.mt940");
+        std::fs::write(&path, MT940).unwrap();
+
+        assert_eq!(read_statements::<2>(&path).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,429 @@
+//! OFX and QFX (Open Financial Exchange) statement import, for both the SGML 1.x format, whose leaf
+//! elements such as `<TRNAMT>-12.34` are not closed, and the XML 2.x format.
+//!
+//! Every `<STMTTRN>` of bank and credit card statements becomes an [`ImportedTransaction`], with the
+//! account from `<ACCTID>` and the currency from `<CURDEF>` of its statement. Amounts are parsed
+//! with [`parse_amount`] and the given [`DecimalSeparator`], if known, as some banks write `-12,34`.
+
+use crate::domain::value_type::currency::Currency;
+use crate::ingest::import::{
+    DateOrder, DecimalSeparator, ImportedTransaction, parse_amount, parse_date, read_text,
+};
+use crate::ingest::statement::{FieldError, ValueError};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum OfxError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("no <OFX> element found")]
+    NotOfx,
+    #[error(transparent)]
+    Element(#[from] FieldError),
+}
+
+/// Reads every transaction of the OFX or QFX file at `path`.
+pub fn read_transactions<const DECIMALS: usize>(
+    path: impl AsRef<Path>,
+    separator: Option<DecimalSeparator>,
+) -> Result<Vec<ImportedTransaction<DECIMALS>>, OfxError> {
+    parse_transactions(&read_text(path)?, separator)
+}
+
+/// Parses every transaction of an OFX or QFX document, failing on the first invalid element.
+///
+/// ```
+/// use fixed_precision_calculations::ingest::ofx::parse_transactions;
+///
+/// let ofx = "OFXHEADER:100\nDATA:OFXSGML\nVERSION:102\n\n<OFX><BANKMSGSRSV1><STMTTRNRS><STMTRS>
+/// <CURDEF>USD<BANKACCTFROM><ACCTID>1234</BANKACCTFROM><BANKTRANLIST>
+/// <STMTTRN><TRNTYPE>DEBIT<DTPOSTED>20250131<TRNAMT>-12.34<FITID>1<NAME>ACME</STMTTRN>
+/// </BANKTRANLIST></STMTRS></STMTTRNRS></BANKMSGSRSV1></OFX>";
+///
+/// let transactions = parse_transactions::<2>(ofx, None).unwrap();
+///
+/// assert_eq!(transactions[0].amount.to_string(), "-12.34");
+/// assert_eq!(transactions[0].payee.as_deref(), Some("ACME"));
+/// ```
+pub fn parse_transactions<const DECIMALS: usize>(
+    text: &str,
+    separator: Option<DecimalSeparator>,
+) -> Result<Vec<ImportedTransaction<DECIMALS>>, OfxError> {
+    let start = text.find("<OFX>").ok_or(OfxError::NotOfx)?;
+    let offset = text[..start].matches('\n').count() as u64;
+
+    let mut transactions = Vec::new();
+    let mut account = None;
+    let mut currency = None;
+    // The line of the open `<STMTTRN>` and its leaf elements.
+    let mut transaction: Option<(u64, HashMap<&str, Leaf>)> = None;
+
+    for token in Tokens::new(&text[start..], offset) {
+        match token {
+            Token::Start {
+                name: "STMTTRN",
+                line,
+            } => transaction = Some((line, HashMap::new())),
+            Token::End { name: "STMTTRN" } => {
+                if let Some((line, elements)) = transaction.take() {
+                    transactions.push(imported(
+                        line,
+                        &elements,
+                        account.clone(),
+                        currency,
+                        separator,
+                    )?);
+                }
+            }
+            Token::Leaf(leaf) => match (&mut transaction, leaf.name) {
+                (Some((_, elements)), name) => {
+                    elements.insert(name, leaf);
+                }
+                (None, "ACCTID") => account = Some(leaf.value),
+                (None, "CURDEF") => {
+                    currency = Some(
+                        leaf.value
+                            .parse()
+                            .map_err(|error| leaf.error(ValueError::Currency(error)))?,
+                    );
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    Ok(transactions)
+}
+
+fn imported<const DECIMALS: usize>(
+    line: u64,
+    elements: &HashMap<&str, Leaf>,
+    account: Option<String>,
+    currency: Option<Currency>,
+    separator: Option<DecimalSeparator>,
+) -> Result<ImportedTransaction<DECIMALS>, FieldError> {
+    let required = |name: &'static str| {
+        elements
+            .get(name)
+            .ok_or_else(|| FieldError::new(line, "<STMTTRN>", ValueError::Missing(name)))
+    };
+    let text = |name: &str| elements.get(name).map(|leaf| leaf.value.clone());
+
+    let date = required("DTPOSTED")?;
+    let amount = required("TRNAMT")?;
+
+    Ok(ImportedTransaction {
+        account,
+        fitid: text("FITID"),
+        // `YYYYMMDD` optionally followed by a time and time zone, e.g. `20250131120000.000[-5:EST]`.
+        date: date
+            .value
+            .get(..8)
+            .and_then(|value| parse_date(value, DateOrder::default()))
+            .ok_or_else(|| date.error(ValueError::invalid(&date.value, "a YYYYMMDD date")))?,
+        amount: parse_amount(&amount.value, separator)
+            .map_err(|error| amount.error(ValueError::Amount(error)))?,
+        currency,
+        // Either `<NAME>` or the `<NAME>` of a `<PAYEE>` aggregate.
+        payee: text("NAME"),
+        memo: text("MEMO"),
+        check_number: text("CHECKNUM"),
+    })
+}
+
+/// An element holding a value, e.g. `<TRNAMT>-12.34`.
+#[derive(Debug, PartialEq)]
+struct Leaf<'a> {
+    name: &'a str,
+    value: String,
+    line: u64,
+}
+
+impl Leaf<'_> {
+    fn error(&self, kind: ValueError) -> FieldError {
+        FieldError::new(self.line, format!("<{}>", self.name), kind)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Start { name: &'a str, line: u64 },
+    End { name: &'a str },
+    Leaf(Leaf<'a>),
+}
+
+/// Splits OFX into start tags, end tags and leaf elements, skipping processing instructions and
+/// comments. The end tag of a leaf element is optional, as in SGML.
+struct Tokens<'a> {
+    text: &'a str,
+    position: usize,
+    line: u64,
+}
+
+impl<'a> Tokens<'a> {
+    /// `offset` is the number of lines before `text`.
+    fn new(text: &'a str, offset: u64) -> Self {
+        Self {
+            text,
+            position: 0,
+            line: offset + 1,
+        }
+    }
+
+    fn advance(&mut self, to: usize) {
+        self.line += self.text[self.position..to].matches('\n').count() as u64;
+        self.position = to;
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let open = self.position + self.text[self.position..].find('<')?;
+            let close = open + self.text[open..].find('>')?;
+            self.advance(open);
+            let tag = &self.text[open + 1..close];
+            let line = self.line;
+
+            let end = close
+                + 1
+                + self.text[close + 1..]
+                    .find('<')
+                    .unwrap_or(self.text.len() - close - 1);
+            let value = self.text[close + 1..end].trim();
+            self.advance(close + 1);
+
+            if tag.starts_with(['?', '!']) {
+                continue;
+            }
+            if let Some(name) = tag.strip_prefix('/') {
+                return Some(Token::End { name: name.trim() });
+            }
+
+            let name = tag.trim();
+            if value.is_empty() {
+                return Some(Token::Start { name, line });
+            }
+
+            self.advance(end);
+            // Skip the end tag of an XML leaf element.
+            let end_tag = format!("</{name}>");
+            if self.text[end..].starts_with(&end_tag) {
+                self.advance(end + end_tag.len());
+            }
+            return Some(Token::Leaf(Leaf {
+                name,
+                value: unescape(value),
+                line,
+            }));
+        }
+    }
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldError, OfxError, ValueError, parse_transactions, read_transactions};
+    use crate::domain::value_type::amount::{Amount, ParseAmountError};
+    use crate::domain::value_type::currency::Currency;
+    use crate::ingest::import::{DecimalSeparator, Deduplicator};
+    use crate::ingest::test_support::date;
+
+    const SGML: &str = "\
+OFXHEADER:100
+DATA:OFXSGML
+VERSION:102
+SECURITY:NONE
+ENCODING:USASCII
+CHARSET:1252
+
+<OFX>
+<SIGNONMSGSRSV1><SONRS><STATUS><CODE>0<SEVERITY>INFO</STATUS><DTSERVER>20250201</SONRS></SIGNONMSGSRSV1>
+<BANKMSGSRSV1>
+<STMTTRNRS>
+<STMTRS>
+<CURDEF>USD
+<BANKACCTFROM>
+<BANKID>121000248
+<ACCTID>1234567890
+<ACCTTYPE>CHECKING
+</BANKACCTFROM>
+<BANKTRANLIST>
+<DTSTART>20250101
+<DTEND>20250131
+<STMTTRN>
+<TRNTYPE>DEBIT
+<DTPOSTED>20250130120000.000[-5:EST]
+<TRNAMT>-1,234.56
+<FITID>2025013001
+<NAME>ACME &amp; Sons
+<MEMO>Invoice 42
+</STMTTRN>
+<STMTTRN>
+<TRNTYPE>CHECK
+<DTPOSTED>20250131
+<TRNAMT>-50.00
+<FITID>2025013101
+<CHECKNUM>1001
+<NAME>Landlord
+</STMTTRN>
+</BANKTRANLIST>
+<LEDGERBAL><BALAMT>1000.00<DTASOF>20250131</LEDGERBAL>
+</STMTRS>
+</STMTTRNRS>
+</BANKMSGSRSV1>
+</OFX>
+";
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<?OFX OFXHEADER="200" VERSION="220" SECURITY="NONE" OLDFILEUID="NONE" NEWFILEUID="NONE"?>
+<OFX>
+  <CREDITCARDMSGSRSV1>
+    <CCSTMTTRNRS>
+      <CCSTMTRS>
+        <CURDEF>EUR</CURDEF>
+        <CCACCTFROM><ACCTID>4111111111111111</ACCTID></CCACCTFROM>
+        <BANKTRANLIST>
+          <STMTTRN>
+            <TRNTYPE>CREDIT</TRNTYPE>
+            <DTPOSTED>20250115</DTPOSTED>
+            <TRNAMT>12,30</TRNAMT>
+            <FITID>A-1</FITID>
+            <NAME>Refund</NAME>
+            <BANKACCTTO><ACCTID>999</ACCTID></BANKACCTTO>
+          </STMTTRN>
+          <STMTTRN>
+            <TRNTYPE>CREDIT</TRNTYPE>
+            <DTPOSTED>20250115</DTPOSTED>
+            <TRNAMT>12,30</TRNAMT>
+            <FITID>A-1</FITID>
+            <NAME>Refund</NAME>
+          </STMTTRN>
+        </BANKTRANLIST>
+      </CCSTMTRS>
+    </CCSTMTTRNRS>
+  </CREDITCARDMSGSRSV1>
+</OFX>
+"#;
+
+    fn element_error(ofx: &str) -> FieldError {
+        match parse_transactions::<2>(ofx, None) {
+            Err(OfxError::Element(error)) => error,
+            result => panic!("expected an element error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn sgml() {
+        let transactions = parse_transactions::<2>(SGML, None).unwrap();
+        let [invoice, check] = transactions.as_slice() else {
+            panic!("expected 2 transactions");
+        };
+
+        assert_eq!(invoice.account.as_deref(), Some("1234567890"));
+        assert_eq!(invoice.fitid.as_deref(), Some("2025013001"));
+        assert_eq!(invoice.date, date("2025-01-30"));
+        assert_eq!(invoice.amount, Amount::new_scaled_i32(-123_456));
+        assert_eq!(invoice.currency, Some(Currency::USD));
+        assert_eq!(invoice.payee.as_deref(), Some("ACME & Sons"));
+        assert_eq!(invoice.memo.as_deref(), Some("Invoice 42"));
+
+        assert_eq!(check.check_number.as_deref(), Some("1001"));
+        assert_eq!(check.memo, None);
+    }
+
+    #[test]
+    fn xml() {
+        let transactions = parse_transactions::<2>(XML, None).unwrap();
+
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].account.as_deref(), Some("4111111111111111"));
+        assert_eq!(transactions[0].amount, Amount::new_scaled_i32(1230));
+        assert_eq!(transactions[0].currency, Some(Currency::EUR));
+        assert_eq!(Deduplicator::new().retain_new(transactions).len(), 1);
+    }
+
+    #[test]
+    fn separator_hint() {
+        let xml = XML.replace("<TRNAMT>12,30", "<TRNAMT>1.050");
+        let transactions = parse_transactions::<2>(&xml, Some(DecimalSeparator::Comma)).unwrap();
+
+        assert_eq!(transactions[0].amount, Amount::new_scaled_i32(105_000));
+        assert_eq!(
+            element_error(&xml).kind,
+            ValueError::Amount(ParseAmountError::Invalid("1.050".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid_elements() {
+        assert_eq!(
+            element_error(&SGML.replace("-50.00", "-50.0001")),
+            FieldError {
+                line: 33,
+                field: "<TRNAMT>".to_string(),
+                kind: ValueError::Amount(ParseAmountError::Scale {
+                    input: "-50.0001".to_string(),
+                    found: 4,
+                    expected: 2,
+                }),
+            }
+        );
+        assert_eq!(
+            element_error(&XML.replacen("<DTPOSTED>20250115", "<DTPOSTED>15.01.2025", 1)),
+            FieldError {
+                line: 12,
+                field: "<DTPOSTED>".to_string(),
+                kind: ValueError::Invalid {
+                    value: "15.01.2025".to_string(),
+                    expected: "a YYYYMMDD date",
+                },
+            }
+        );
+        assert_eq!(
+            element_error(&SGML.replace("<TRNAMT>-50.00\n", "")),
+            FieldError {
+                line: 30,
+                field: "<STMTTRN>".to_string(),
+                kind: ValueError::Missing("TRNAMT"),
+            }
+        );
+        assert!(matches!(
+            element_error(&XML.replace(">EUR<", ">euro<")).kind,
+            ValueError::Currency(_)
+        ));
+        assert!(matches!(
+            parse_transactions::<2>("TRNAMT,DTPOSTED\n1.00,20250101", None),
+            Err(OfxError::NotOfx)
+        ));
+    }
+
+    #[test]
+    fn file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("statement.ofx");
+        let latin1: Vec<u8> = SGML
+            .replace("ACME &amp;", "ACME \u{e9}")
+            .chars()
+            .map(|char| u8::try_from(char).unwrap())
+            .collect();
+        std::fs::write(&path, latin1).unwrap();
+
+        let transactions = read_transactions::<2>(&path, None).unwrap();
+
+        assert_eq!(transactions[0].payee.as_deref(), Some("ACME \u{e9} Sons"));
+    }
+}
@@ -0,0 +1,337 @@
+//! QIF (Quicken Interchange Format) import.
+//!
+//! Transactions of `!Type:Bank`, `Cash`, `CCard`, `Oth A` and `Oth L` lists become
+//! [`ImportedTransaction`]s, with the account named by the preceding `!Account` block, if any. Lines
+//! before the first `!Type:` header are read as bank transactions; investment, category, class and
+//! memorized lists are skipped. Split lines (`S`, `E`, `$`) are ignored, as the `T` line holds the
+//! total. QIF dates and amounts follow the locale of the exporting program, so they are parsed with
+//! [`parse_date`] in the given [`DateOrder`] and with [`parse_amount`] and the given
+//! [`DecimalSeparator`], if known.
+
+use crate::ingest::import::{
+    DateOrder, DecimalSeparator, ImportedTransaction, parse_amount, parse_date, read_text,
+};
+use crate::ingest::statement::{FieldError, ValueError};
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum QifError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Field(#[from] FieldError),
+    #[error("line {line}: transaction has no '{code}' field")]
+    MissingField {
+        /// The line ending the transaction.
+        line: u64,
+        code: char,
+    },
+}
+
+/// Reads every transaction of the QIF file at `path`.
+pub fn read_transactions<const DECIMALS: usize>(
+    path: impl AsRef<Path>,
+    order: DateOrder,
+    separator: Option<DecimalSeparator>,
+) -> Result<Vec<ImportedTransaction<DECIMALS>>, QifError> {
+    parse_transactions(&read_text(path)?, order, separator)
+}
+
+/// Parses every transaction of a QIF file, failing on the first invalid field.
+///
+/// ```
+/// use fixed_precision_calculations::ingest::import::{DateOrder, DecimalSeparator};
+/// use fixed_precision_calculations::ingest::qif::parse_transactions;
+///
+/// let qif = "!Type:Bank\nD31.01.2025\nT-1.234,56\nPACME\n^\n";
+///
+/// let transactions =
+///     parse_transactions::<2>(qif, DateOrder::DayFirst, Some(DecimalSeparator::Comma)).unwrap();
+///
+/// assert_eq!(transactions[0].amount.to_string(), "-1234.56");
+/// assert_eq!(transactions[0].payee.as_deref(), Some("ACME"));
+/// ```
+pub fn parse_transactions<const DECIMALS: usize>(
+    text: &str,
+    order: DateOrder,
+    separator: Option<DecimalSeparator>,
+) -> Result<Vec<ImportedTransaction<DECIMALS>>, QifError> {
+    let mut transactions = Vec::new();
+    let mut section = Section::Transactions;
+    let mut account: Option<String> = None;
+    let mut record = Record::default();
+
+    for (index, line) in text.lines().enumerate() {
+        let number = index as u64 + 1;
+        let line = line.trim_end();
+        let Some(code) = line.chars().next() else {
+            continue;
+        };
+        let value = line[code.len_utf8()..].trim();
+
+        if code == '!' {
+            section = Section::from_header(value);
+            record = Record::default();
+            continue;
+        }
+        if code == '^' {
+            if let (Section::Transactions, false) = (section, record.is_empty()) {
+                transactions.push(record.transaction(number, account.clone(), order, separator)?);
+            }
+            record = Record::default();
+            continue;
+        }
+
+        match (section, code) {
+            (Section::Account, 'N') => account = Some(value.to_string()),
+            (Section::Transactions, 'D') => record.date = Some((number, value)),
+            // `U` is a duplicate of `T` written by newer versions of Quicken.
+            (Section::Transactions, 'T') => record.amount = Some((number, value)),
+            (Section::Transactions, 'U') if record.amount.is_none() => {
+                record.amount = Some((number, value))
+            }
+            (Section::Transactions, 'P') => record.payee = Some(value),
+            (Section::Transactions, 'M') => record.memo = Some(value),
+            (Section::Transactions, 'N') => record.check_number = Some(value),
+            _ => {}
+        }
+    }
+
+    if !record.is_empty() && section == Section::Transactions {
+        let end = text.lines().count() as u64;
+        transactions.push(record.transaction(end, account, order, separator)?);
+    }
+    Ok(transactions)
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Section {
+    Transactions,
+    Account,
+    Skipped,
+}
+
+impl Section {
+    /// The section started by a header such as `Type:Bank`, without its `!`.
+    fn from_header(header: &str) -> Self {
+        match header.strip_prefix("Type:").map(str::trim) {
+            Some("Bank" | "Cash" | "CCard" | "Oth A" | "Oth L") => Self::Transactions,
+            _ if header == "Account" => Self::Account,
+            _ => Self::Skipped,
+        }
+    }
+}
+
+/// The fields of one transaction, with the lines of the date and amount.
+#[derive(Debug, Default)]
+struct Record<'a> {
+    date: Option<(u64, &'a str)>,
+    amount: Option<(u64, &'a str)>,
+    payee: Option<&'a str>,
+    memo: Option<&'a str>,
+    check_number: Option<&'a str>,
+}
+
+impl Record<'_> {
+    fn is_empty(&self) -> bool {
+        self.date.is_none()
+            && self.amount.is_none()
+            && self.payee.is_none()
+            && self.memo.is_none()
+            && self.check_number.is_none()
+    }
+
+    /// `end` is the line ending the transaction.
+    fn transaction<const DECIMALS: usize>(
+        &self,
+        end: u64,
+        account: Option<String>,
+        order: DateOrder,
+        separator: Option<DecimalSeparator>,
+    ) -> Result<ImportedTransaction<DECIMALS>, QifError> {
+        let missing = |code| QifError::MissingField { line: end, code };
+        let (date_line, date) = self.date.ok_or_else(|| missing('D'))?;
+        let (amount_line, amount) = self.amount.ok_or_else(|| missing('T'))?;
+        let text =
+            |value: Option<&str>| value.filter(|value| !value.is_empty()).map(str::to_string);
+
+        Ok(ImportedTransaction {
+            account,
+            fitid: None,
+            date: parse_date(date, order).ok_or_else(|| {
+                let expected = match order {
+                    DateOrder::MonthFirst => "a month-first date such as 01/31/2025",
+                    DateOrder::DayFirst => "a day-first date such as 31.01.2025",
+                };
+                FieldError::new(date_line, "D", ValueError::invalid(date, expected))
+            })?,
+            amount: parse_amount(amount, separator)
+                .map_err(|error| FieldError::new(amount_line, "T", error))?,
+            currency: None,
+            payee: text(self.payee),
+            memo: text(self.memo),
+            check_number: text(self.check_number),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldError, QifError, ValueError, parse_transactions, read_transactions};
+    use crate::domain::value_type::amount::{Amount, ParseAmountError};
+    use crate::ingest::import::{DateOrder, DecimalSeparator};
+    use crate::ingest::test_support::date;
+
+    const QIF: &str = "\
+!Option:AutoSwitch
+!Account
+NChecking
+TBank
+^
+!Clear:AutoSwitch
+!Type:Bank
+D1/30'25
+T-1,234.56
+U-1,234.56
+CX
+N1001
+PACME Corp
+MInvoice 42
+LOffice:Supplies
+SOffice:Supplies
+$-1,000.00
+SOffice:Postage
+$-234.56
+^
+D01/31/2025
+T2,500.00
+PSalary
+^
+!Type:Cat
+NOffice
+D Office expenses
+E
+^
+!Type:Invst
+D1/31'25
+NBuy
+YACME
+I10.00
+Q5
+T50.00
+^
+";
+
+    fn field_error(qif: &str, order: DateOrder) -> FieldError {
+        match parse_transactions::<2>(qif, order, None) {
+            Err(QifError::Field(error)) => error,
+            result => panic!("expected a field error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn bank_transactions() {
+        let transactions = parse_transactions::<2>(QIF, DateOrder::MonthFirst, None).unwrap();
+        let [invoice, salary] = transactions.as_slice() else {
+            panic!("expected 2 transactions");
+        };
+
+        assert_eq!(invoice.account.as_deref(), Some("Checking"));
+        assert_eq!(invoice.date, date("2025-01-30"));
+        assert_eq!(invoice.amount, Amount::new_scaled_i32(-123_456));
+        assert_eq!(invoice.payee.as_deref(), Some("ACME Corp"));
+        assert_eq!(invoice.memo.as_deref(), Some("Invoice 42"));
+        assert_eq!(invoice.check_number.as_deref(), Some("1001"));
+        assert_eq!(invoice.fitid, None);
+
+        assert_eq!(salary.date, date("2025-01-31"));
+        assert_eq!(salary.amount, Amount::new_scaled_i32(250_000));
+        assert_eq!(salary.memo, None);
+    }
+
+    #[test]
+    fn european_export_without_header() {
+        let qif = "D31.01.2025\nT-1.234,56\nPB\u{e4}ckerei\n^\nD01.02.2025\nT12,5";
+        let transactions = parse_transactions::<2>(qif, DateOrder::DayFirst, None).unwrap();
+
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].date, date("2025-01-31"));
+        assert_eq!(transactions[0].amount, Amount::new_scaled_i32(-123_456));
+        assert_eq!(transactions[0].account, None);
+        assert_eq!(transactions[1].date, date("2025-02-01"));
+        assert_eq!(transactions[1].amount, Amount::new_scaled_i32(1250));
+    }
+
+    #[test]
+    fn padded_quicken_date_and_separator_hint() {
+        let qif = "!Type:Bank\nD1/ 5' 5\nT-1,234\n^\n";
+        let transactions =
+            parse_transactions::<2>(qif, DateOrder::MonthFirst, Some(DecimalSeparator::Point))
+                .unwrap();
+
+        assert_eq!(transactions[0].date, date("2005-01-05"));
+        assert_eq!(transactions[0].amount, Amount::new_scaled_i32(-123_400));
+        // Without a hint, `1,234` could also be 1.234.
+        assert_eq!(
+            field_error(qif, DateOrder::MonthFirst).kind,
+            ValueError::Amount(ParseAmountError::Invalid("-1,234".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid_fields() {
+        assert_eq!(
+            field_error(QIF, DateOrder::DayFirst),
+            FieldError {
+                line: 8,
+                field: "D".to_string(),
+                kind: ValueError::Invalid {
+                    value: "1/30'25".to_string(),
+                    expected: "a day-first date such as 31.01.2025",
+                },
+            }
+        );
+        assert_eq!(
+            field_error(
+                &QIF.replace("T2,500.00", "T2,500.005"),
+                DateOrder::MonthFirst
+            ),
+            FieldError {
+                line: 22,
+                field: "T".to_string(),
+                kind: ValueError::Amount(ParseAmountError::Scale {
+                    input: "2,500.005".to_string(),
+                    found: 3,
+                    expected: 2,
+                }),
+            }
+        );
+        assert!(matches!(
+            parse_transactions::<2>(&QIF.replace("T2,500.00\n", ""), DateOrder::MonthFirst, None),
+            Err(QifError::MissingField {
+                line: 23,
+                code: 'T'
+            })
+        ));
+    }
+
+    #[test]
+    fn file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("statement.qif");
+        std::fs::write(
+            &path,
+            b"!Type:CCard\nD2025-01-31\nT-9.99\nPB\xe4ckerei\nM\x80 9.99 \x96 Baker\x92s\n^\n",
+        )
+        .unwrap();
+
+        let transactions = read_transactions::<2>(&path, DateOrder::DayFirst, None).unwrap();
+
+        assert_eq!(transactions[0].payee.as_deref(), Some("B\u{e4}ckerei"));
+        // Windows-1252 rather than Latin-1, which has control characters at 0x80-0x9F.
+        assert_eq!(
+            transactions[0].memo.as_deref(),
+            Some("\u{20ac} 9.99 \u{2013} Baker\u{2019}s")
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::domain::transaction::Direction;
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+use crate::domain::value_type::currency::{Currency, ParseCurrencyError};
+use crate::domain::value_type::money::Money;
+
+/// A value of an imported file that could not be read, e.g. `line 12, <Amt>: '12.345' has 3
+/// decimals, expected at most 2`.
+#[derive(Debug, thiserror::Error, PartialEq)]
+#[error("line {line}, {field}: {kind}")]
+pub struct FieldError {
+    /// 1-based line of the value in the file.
+    pub line: u64,
+    /// The field as written in the file, e.g. `<Amt>`, `:61:` or QIF's `T`.
+    pub field: String,
+    pub kind: ValueError,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ValueError {
+    #[error(transparent)]
+    Amount(#[from] ParseAmountError),
+    #[error(transparent)]
+    Currency(#[from] ParseCurrencyError),
+    /// A missing child element, e.g. `Amt` of `<Ntry>`.
+    #[error("missing <{0}>")]
+    Missing(&'static str),
+    #[error("invalid value '{value}', expected {expected}")]
+    Invalid {
+        value: String,
+        expected: &'static str,
+    },
+    #[error("currency {found} differs from the statement currency {expected}")]
+    CurrencyMismatch { expected: Currency, found: Currency },
+}
+
+/// A bank statement whose opening balance plus entries is not its closing balance.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Unbalanced {
+    #[error(
+        "statement '{statement}' does not balance: closing balance {closing}, opening balance plus entries {computed}"
+    )]
+    Mismatch {
+        statement: String,
+        closing: String,
+        computed: String,
+    },
+    #[error("statement '{statement}' does not balance: opening balance plus entries overflows")]
+    Overflow { statement: String },
+}
+
+impl FieldError {
+    pub(crate) fn new(line: u64, field: impl Into<String>, kind: impl Into<ValueError>) -> Self {
+        Self {
+            line,
+            field: field.into(),
+            kind: kind.into(),
+        }
+    }
+}
+
+impl ValueError {
+    pub(crate) fn invalid(value: impl Into<String>, expected: &'static str) -> Self {
+        Self::Invalid {
+            value: value.into(),
+            expected,
+        }
+    }
+}
+
+/// The amount of an entry given as a magnitude and a [`Direction`], as statement formats do,
+/// negative for a debit.
+pub fn signed_amount<const DECIMALS: usize>(
+    amount: Money<DECIMALS>,
+    direction: Direction,
+) -> Money<DECIMALS> {
+    match direction {
+        Direction::Debit => -amount,
+        Direction::Credit => amount,
+    }
+}
+
+/// Checks that `opening` plus the signed `entries` equals `closing`, the continuity every bank
+/// statement format guarantees. A debit balance of zero equals a credit balance of zero. Entries too
+/// large to add up are [`Unbalanced::Overflow`] rather than a panic, as statement files are
+/// untrusted input.
+pub fn check_continuity<const DECIMALS: usize>(
+    statement: &str,
+    opening: Money<DECIMALS>,
+    entries: impl IntoIterator<Item = Amount<DECIMALS>>,
+    closing: Money<DECIMALS>,
+) -> Result<(), Unbalanced> {
+    let total = entries
+        .into_iter()
+        .try_fold(opening.amount(), Amount::checked_add)
+        .ok_or_else(|| Unbalanced::Overflow {
+            statement: statement.to_string(),
+        })?;
+    let computed = Money::new(total, opening.currency());
+
+    match computed.checked_sub(closing) {
+        Ok(difference) if difference.amount().is_zero() => Ok(()),
+        _ => Err(Unbalanced::Mismatch {
+            statement: statement.to_string(),
+            closing: closing.to_string(),
+            computed: computed.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Unbalanced, check_continuity};
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use crate::domain::value_type::money::Money;
+    use crate::ingest::test_support::eur;
+    use fastnum::D128;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("100.00", &["-12.34", "2.34"], "90.00")]
+    #[case("-0.00", &[], "0.00")]
+    #[case("0.00", &["10.00", "-10.00"], "-0.00")]
+    fn balances(#[case] opening: &str, #[case] entries: &[&str], #[case] closing: &str) {
+        let entries = entries.iter().map(|entry| entry.parse().unwrap());
+
+        assert_eq!(
+            check_continuity("1", eur(opening), entries, eur(closing)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn unbalanced() {
+        let entries = ["-12.34".parse().unwrap()];
+
+        assert_eq!(
+            check_continuity("1", eur("100.00"), entries, eur("87.67")),
+            Err(Unbalanced::Mismatch {
+                statement: "1".to_string(),
+                closing: "87.67 EUR".to_string(),
+                computed: "87.66 EUR".to_string(),
+            })
+        );
+        assert!(
+            check_continuity(
+                "1",
+                eur("1.00"),
+                [],
+                Money::new("1.00".parse().unwrap(), Currency::CHF)
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let max = Amount::<2>::from(D128::MAX);
+
+        assert_eq!(
+            check_continuity("1", eur("1.00"), [max], eur("1.00")),
+            Err(Unbalanced::Overflow {
+                statement: "1".to_string()
+            })
+        );
+        // A closing balance the total cannot be compared with does not balance either.
+        assert!(matches!(
+            check_continuity("1", eur("0.00"), [-max], Money::new(max, Currency::EUR)),
+            Err(Unbalanced::Mismatch { .. })
+        ));
+    }
+}
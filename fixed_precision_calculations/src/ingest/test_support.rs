@@ -0,0 +1,15 @@
+//! Helpers shared by the importers' tests.
+
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::money::Money;
+use chrono::NaiveDate;
+
+/// Euros from a decimal string such as "-12.34".
+pub(crate) fn eur(amount: &str) -> Money<2> {
+    Money::new(amount.parse().unwrap(), Currency::EUR)
+}
+
+/// A date from an ISO string such as "2025-01-31".
+pub(crate) fn date(date: &str) -> NaiveDate {
+    date.parse().unwrap()
+}
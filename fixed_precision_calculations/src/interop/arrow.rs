@@ -0,0 +1,270 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::scaled_amount::{MinorUnitsError, ScaledAmount};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Decimal128Type;
+use arrow_array::{Array, ArrayRef, Decimal128Array, RecordBatch};
+use arrow_schema::{ArrowError, DECIMAL128_MAX_PRECISION, DataType, Field, Schema};
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::errors::ParquetError;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArrowAmountError {
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+    #[error(transparent)]
+    Parquet(#[from] ParquetError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("amount at index {index}: {source}")]
+    Amount {
+        index: usize,
+        source: MinorUnitsError,
+    },
+    #[error("expected Decimal128 with scale {expected}, found {found}")]
+    DataType { expected: usize, found: DataType },
+    #[error("null at index {0}")]
+    Null(usize),
+    #[error("missing column '{0}'")]
+    MissingColumn(String),
+}
+
+/// The Arrow type of amounts with `DECIMALS` decimals: `Decimal128(38, DECIMALS)`.
+pub const fn amount_data_type<const DECIMALS: usize>() -> DataType {
+    DataType::Decimal128(DECIMAL128_MAX_PRECISION, DECIMALS as i8)
+}
+
+/// A nullable amount column, e.g. for a [`Schema`].
+pub fn amount_field<const DECIMALS: usize>(name: &str) -> Field {
+    Field::new(name, amount_data_type::<DECIMALS>(), true)
+}
+
+/// Converts amounts into a [`Decimal128Array`] of their minor units.
+///
+/// Fails, naming the index, if an amount holds more than `DECIMALS` decimals or more digits than
+/// 38.
+pub fn to_decimal128_array<const DECIMALS: usize>(
+    amounts: &[Amount<DECIMALS>],
+) -> Result<Decimal128Array, ArrowAmountError> {
+    let minor_units = amounts
+        .iter()
+        .enumerate()
+        .map(|(index, &amount)| minor_units(index, amount))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    with_amount_type::<DECIMALS>(Decimal128Array::from(minor_units))
+}
+
+/// Like [`to_decimal128_array`], with `None` as null.
+pub fn to_nullable_decimal128_array<const DECIMALS: usize>(
+    amounts: &[Option<Amount<DECIMALS>>],
+) -> Result<Decimal128Array, ArrowAmountError> {
+    let minor_units = amounts
+        .iter()
+        .enumerate()
+        .map(|(index, amount)| amount.map(|amount| minor_units(index, amount)).transpose())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    with_amount_type::<DECIMALS>(Decimal128Array::from(minor_units))
+}
+
+/// Reads the amounts back, failing on nulls or on a scale other than `DECIMALS`.
+pub fn from_decimal128_array<const DECIMALS: usize>(
+    array: &Decimal128Array,
+) -> Result<Vec<Amount<DECIMALS>>, ArrowAmountError> {
+    ensure_amount_type::<DECIMALS>(array.data_type())?;
+
+    if let Some(index) = (0..array.len()).find(|&index| array.is_null(index)) {
+        return Err(ArrowAmountError::Null(index));
+    }
+
+    Ok(array.values().iter().copied().map(amount).collect())
+}
+
+/// Reads the amounts back with nulls as `None`, failing on a scale other than `DECIMALS`.
+pub fn from_nullable_decimal128_array<const DECIMALS: usize>(
+    array: &Decimal128Array,
+) -> Result<Vec<Option<Amount<DECIMALS>>>, ArrowAmountError> {
+    ensure_amount_type::<DECIMALS>(array.data_type())?;
+
+    Ok(array
+        .iter()
+        .map(|minor_units| minor_units.map(amount))
+        .collect())
+}
+
+/// Writes amount columns of equal length, with `None` as null, to a Parquet file at `path`.
+pub fn write_parquet<const DECIMALS: usize>(
+    path: impl AsRef<Path>,
+    columns: &[(&str, &[Option<Amount<DECIMALS>>])],
+) -> Result<(), ArrowAmountError> {
+    let schema = Arc::new(Schema::new(
+        columns
+            .iter()
+            .map(|(name, _)| amount_field::<DECIMALS>(name))
+            .collect::<Vec<_>>(),
+    ));
+    let arrays = columns
+        .iter()
+        .map(|(_, amounts)| Ok(Arc::new(to_nullable_decimal128_array(amounts)?) as ArrayRef))
+        .collect::<Result<Vec<_>, ArrowAmountError>>()?;
+    let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+
+    let mut writer = ArrowWriter::try_new(File::create(path)?, schema, None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Reads the amount column `column` of a Parquet file at `path`, with nulls as `None`.
+pub fn read_parquet<const DECIMALS: usize>(
+    path: impl AsRef<Path>,
+    column: &str,
+) -> Result<Vec<Option<Amount<DECIMALS>>>, ArrowAmountError> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()?;
+
+    let mut amounts = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        let array = batch
+            .column_by_name(column)
+            .ok_or_else(|| ArrowAmountError::MissingColumn(column.to_string()))?;
+        let array = array.as_primitive_opt::<Decimal128Type>().ok_or_else(|| {
+            ArrowAmountError::DataType {
+                expected: DECIMALS,
+                found: array.data_type().clone(),
+            }
+        })?;
+        amounts.extend(from_nullable_decimal128_array::<DECIMALS>(array)?);
+    }
+    Ok(amounts)
+}
+
+fn minor_units<const DECIMALS: usize>(
+    index: usize,
+    amount: Amount<DECIMALS>,
+) -> Result<i128, ArrowAmountError> {
+    ScaledAmount::try_from(amount)
+        .map(|amount| amount.minor_units())
+        .map_err(|source| ArrowAmountError::Amount { index, source })
+}
+
+fn amount<const DECIMALS: usize>(minor_units: i128) -> Amount<DECIMALS> {
+    ScaledAmount::<DECIMALS>::new_scaled_i128(minor_units).into()
+}
+
+fn with_amount_type<const DECIMALS: usize>(
+    array: Decimal128Array,
+) -> Result<Decimal128Array, ArrowAmountError> {
+    let array = array.with_precision_and_scale(DECIMAL128_MAX_PRECISION, DECIMALS as i8)?;
+    // Minor units of up to 39 digits fit an `i128`, but not the declared precision.
+    array.validate_decimal_precision(DECIMAL128_MAX_PRECISION)?;
+    Ok(array)
+}
+
+fn ensure_amount_type<const DECIMALS: usize>(data_type: &DataType) -> Result<(), ArrowAmountError> {
+    match data_type {
+        DataType::Decimal128(_, scale) if usize::try_from(*scale) == Ok(DECIMALS) => Ok(()),
+        found => Err(ArrowAmountError::DataType {
+            expected: DECIMALS,
+            found: found.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ArrowAmountError, amount_data_type, from_decimal128_array, from_nullable_decimal128_array,
+        read_parquet, to_decimal128_array, to_nullable_decimal128_array, write_parquet,
+    };
+    use crate::domain::value_type::amount::Amount;
+    use arrow_array::{Array, Decimal128Array};
+    use arrow_schema::DataType;
+
+    fn amounts(values: &[&str]) -> Vec<Amount<2>> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        let amounts = amounts(&["12.34", "-0.01", "0.00", "92233720368547758.08"]);
+        let array = to_decimal128_array(&amounts).unwrap();
+
+        assert_eq!(array.data_type(), &DataType::Decimal128(38, 2));
+        assert_eq!(array.value(0), 1234);
+        assert_eq!(array.value(3), 9_223_372_036_854_775_808);
+        assert_eq!(from_decimal128_array::<2>(&array).unwrap(), amounts);
+    }
+
+    #[test]
+    fn nulls() {
+        let amounts = vec![Some("12.34".parse().unwrap()), None, Some(Amount::ZERO)];
+        let array = to_nullable_decimal128_array::<2>(&amounts).unwrap();
+
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(
+            from_nullable_decimal128_array::<2>(&array).unwrap(),
+            amounts
+        );
+        assert!(matches!(
+            from_decimal128_array::<2>(&array),
+            Err(ArrowAmountError::Null(1))
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            to_decimal128_array(&[Amount::ZERO, Amount::<2>::new_f64(0.1)]),
+            Err(ArrowAmountError::Amount { index: 1, .. })
+        ));
+
+        let too_precise: Amount<0> = "100000000000000000000000000000000000000".parse().unwrap();
+        assert!(matches!(
+            to_decimal128_array(&[too_precise]),
+            Err(ArrowAmountError::Arrow(_))
+        ));
+
+        let array = Decimal128Array::from(vec![1234])
+            .with_precision_and_scale(38, 3)
+            .unwrap();
+        assert!(matches!(
+            from_decimal128_array::<2>(&array),
+            Err(ArrowAmountError::DataType { expected: 2, .. })
+        ));
+        assert_eq!(amount_data_type::<4>(), DataType::Decimal128(38, 4));
+    }
+
+    #[test]
+    fn parquet_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("amounts.parquet");
+        let net = vec![
+            Some("100.00".parse().unwrap()),
+            None,
+            Some("-7.49".parse().unwrap()),
+        ];
+        let vat = vec![
+            Some("19.00".parse().unwrap()),
+            Some("0.01".parse().unwrap()),
+            None,
+        ];
+
+        write_parquet::<2>(&path, &[("net", &net), ("vat", &vat)]).unwrap();
+
+        assert_eq!(read_parquet::<2>(&path, "net").unwrap(), net);
+        assert_eq!(read_parquet::<2>(&path, "vat").unwrap(), vat);
+        assert!(matches!(
+            read_parquet::<2>(&path, "gross"),
+            Err(ArrowAmountError::MissingColumn(column)) if column == "gross"
+        ));
+        assert!(matches!(
+            read_parquet::<3>(&path, "net"),
+            Err(ArrowAmountError::DataType { expected: 3, .. })
+        ));
+    }
+}
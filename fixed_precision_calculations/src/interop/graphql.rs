@@ -0,0 +1,151 @@
+//! `async-graphql` scalars for the value types:
+//!
+//! - `Amount<DECIMALS>` is a scalar named after its scale, e.g. `Money2`, an exact decimal string such
+//!   as "12.34". Input with more than `DECIMALS` decimals, or a number rather than a string, is
+//!   rejected instead of rounded. Amounts of different scales are distinct scalars in one schema.
+//! - `Uid<T>` is the built-in `ID` scalar, so clients need no custom type, but input that is not a
+//!   UUID is rejected.
+
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::uid::Uid;
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, TypeName, Value};
+use std::borrow::Cow;
+
+impl<const DECIMALS: usize> TypeName for Amount<DECIMALS> {
+    fn type_name() -> Cow<'static, str> {
+        format!("Money{DECIMALS}").into()
+    }
+}
+
+/// An exact decimal amount as a string, with at most as many decimals as the number in the scalar's
+/// name, e.g. "12.34" for `Money2`.
+#[Scalar(name_type)]
+impl<const DECIMALS: usize> ScalarType for Amount<DECIMALS> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(text) => text.parse().map_err(InputValueError::custom),
+            value => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[Scalar(name = "ID")]
+impl<T: Send + Sync> ScalarType for Uid<T> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(text) => text.parse().map_err(InputValueError::custom),
+            value => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::monetary_amount::MonetaryAmount;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use crate::domain::value_type::uid::Uid;
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, Variables};
+    use rstest::rstest;
+    use serde_json::json;
+
+    struct Account;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        /// Adds VAT at 19 % to `net`, rounded half to even.
+        async fn gross(&self, net: Amount<2>) -> Amount<2> {
+            net + net.mul_ratio(19, 100, RoundingMode::HalfEven).unwrap()
+        }
+
+        async fn account(&self, id: Uid<Account>) -> Uid<Account> {
+            id
+        }
+
+        async fn exchange_rate(&self) -> Amount<4> {
+            Amount::new_scaled_i32(10_825)
+        }
+    }
+
+    fn execute(query: &str, variables: serde_json::Value) -> serde_json::Value {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let request = async_graphql::Request::new(query).variables(Variables::from_json(variables));
+        let response = pollster::block_on(schema.execute(request));
+
+        serde_json::to_value(response).unwrap()
+    }
+
+    #[test]
+    fn sdl() {
+        let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+
+        assert!(sdl.contains("scalar Money2"), "{sdl}");
+        assert!(sdl.contains("scalar Money4"), "{sdl}");
+        assert!(sdl.contains("as the number in the scalar's"), "{sdl}");
+        assert!(!sdl.contains("scalar ID"), "{sdl}");
+        assert!(sdl.contains("gross(net: Money2!): Money2!"), "{sdl}");
+        assert!(sdl.contains("exchangeRate: Money4!"), "{sdl}");
+        assert!(sdl.contains("account(id: ID!): ID!"), "{sdl}");
+    }
+
+    #[test]
+    fn money() {
+        assert_eq!(
+            execute(r#"{ gross(net: "100.10") }"#, json!({})),
+            json!({"data": {"gross": "119.12"}})
+        );
+        assert_eq!(
+            execute(
+                "query ($net: Money2!) { gross(net: $net) }",
+                json!({"net": "-0.5"})
+            ),
+            json!({"data": {"gross": "-0.60"}})
+        );
+        assert_eq!(
+            execute("{ exchangeRate }", json!({})),
+            json!({"data": {"exchangeRate": "1.0825"}})
+        );
+    }
+
+    #[rstest]
+    #[case(r#"{ gross(net: "12.345") }"#, "has 3 decimals, expected at most 2")]
+    #[case(r#"{ gross(net: "12,34") }"#, "invalid decimal")]
+    #[case("{ gross(net: 12.34) }", "expected type \"Money2\"")]
+    #[case(r#"{ account(id: "not-a-uuid") }"#, "invalid character")]
+    #[case("{ account(id: 1) }", "type \"ID\", found 1")]
+    fn rejects(#[case] query: &str, #[case] message: &str) {
+        let response = execute(query, json!({}));
+        let error = response["errors"][0]["message"].as_str().unwrap();
+
+        assert!(error.contains(message), "{error}");
+        assert!(response["data"].is_null());
+    }
+
+    #[test]
+    fn id() {
+        let id = Uid::<Account>::new_v4().to_string();
+
+        assert_eq!(
+            execute("query ($id: ID!) { account(id: $id) }", json!({ "id": id })),
+            json!({"data": {"account": id}})
+        );
+    }
+}
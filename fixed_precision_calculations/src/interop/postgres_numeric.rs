@@ -0,0 +1,340 @@
+//! The binary wire format of Postgres `NUMERIC`, so that amounts need not round-trip through text.
+//!
+//! A value is a header of four big-endian 16-bit fields, followed by `ndigits` base-10 000 digits:
+//!
+//! | field     | meaning                                                           |
+//! |-----------|-------------------------------------------------------------------|
+//! | `ndigits` | number of digits that follow                                      |
+//! | `weight`  | power of 10 000 of the first digit, e.g. 0 for 1234.5, -1 for 0.5 |
+//! | `sign`    | `0x0000` positive, `0x4000` negative, `0xC000` NaN, ...           |
+//! | `dscale`  | decimals to display, e.g. 2 for 12.30                             |
+//!
+//! Leading and trailing zero digits are left out, so 12.30 is digits `[12, 3000]` with weight 0.
+
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+
+const POSITIVE: u16 = 0x0000;
+const NEGATIVE: u16 = 0x4000;
+const NAN: u16 = 0xC000;
+const POSITIVE_INFINITY: u16 = 0xD000;
+const NEGATIVE_INFINITY: u16 = 0xF000;
+const DIGITS_PER_GROUP: usize = 4;
+const HEADER_LEN: usize = 8;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum PgNumericError {
+    #[error("expected {expected} bytes of NUMERIC, found {found}")]
+    Length { expected: usize, found: usize },
+    #[error("NUMERIC {0} is not a finite amount")]
+    NotFinite(&'static str),
+    #[error("invalid NUMERIC sign {0:#06x}")]
+    Sign(u16),
+    #[error("invalid NUMERIC digit {0}, expected 0..=9999")]
+    Digit(i16),
+    #[error(transparent)]
+    Amount(#[from] ParseAmountError),
+}
+
+/// Encodes `amount` exactly, with as many display decimals (`dscale`) as it holds.
+pub fn encode<const DECIMALS: usize, const N: usize>(amount: &Amount<DECIMALS, N>) -> Vec<u8> {
+    let raw = amount.raw();
+    let scale = raw.fractional_digits_count();
+    let mut digits = raw.digits().to_string();
+    // A negative scale means trailing zeros that are not stored, e.g. 12 × 10^3.
+    digits.extend(std::iter::repeat_n('0', (-scale).max(0) as usize));
+    let scale = scale.max(0) as usize;
+
+    // Left-pad so there is at least one integer digit, then pad both parts to whole groups.
+    let integer_len = digits.len().saturating_sub(scale).max(1);
+    let digits = format!("{digits:0>width$}", width = integer_len + scale);
+    let (integer, fraction) = digits.split_at(integer_len);
+    let integer = format!(
+        "{integer:0>width$}",
+        width = integer_len.next_multiple_of(DIGITS_PER_GROUP)
+    );
+    let fraction = format!(
+        "{fraction:0<width$}",
+        width = scale.next_multiple_of(DIGITS_PER_GROUP)
+    );
+
+    let mut groups: Vec<i16> = integer
+        .as_bytes()
+        .chunks(DIGITS_PER_GROUP)
+        .chain(fraction.as_bytes().chunks(DIGITS_PER_GROUP))
+        .map(|group| {
+            group
+                .iter()
+                .fold(0, |value, digit| value * 10 + i16::from(digit - b'0'))
+        })
+        .collect();
+    let mut weight = (integer.len() / DIGITS_PER_GROUP) as i16 - 1;
+
+    let leading_zeros = groups.iter().take_while(|&&group| group == 0).count();
+    groups.drain(..leading_zeros);
+    weight -= leading_zeros as i16;
+    while groups.last() == Some(&0) {
+        groups.pop();
+    }
+    if groups.is_empty() {
+        weight = 0;
+    }
+
+    let sign = if amount.is_negative() && !groups.is_empty() {
+        NEGATIVE
+    } else {
+        POSITIVE
+    };
+
+    let mut bytes = Vec::with_capacity(HEADER_LEN + groups.len() * 2);
+    bytes.extend((groups.len() as i16).to_be_bytes());
+    bytes.extend(weight.to_be_bytes());
+    bytes.extend(sign.to_be_bytes());
+    bytes.extend((scale as u16).to_be_bytes());
+    for group in groups {
+        bytes.extend(group.to_be_bytes());
+    }
+    bytes
+}
+
+/// Decodes a finite `NUMERIC`, failing like parsing does if it has non-zero digits beyond
+/// `DECIMALS`, e.g. 12.345 into an `Amount<2>`, but accepting trailing zeros, e.g. 12.3400.
+pub fn decode<const DECIMALS: usize, const N: usize>(
+    bytes: &[u8],
+) -> Result<Amount<DECIMALS, N>, PgNumericError> {
+    let field = |index: usize| -> Result<[u8; 2], PgNumericError> {
+        bytes
+            .get(index * 2..index * 2 + 2)
+            .map(|field| [field[0], field[1]])
+            .ok_or(PgNumericError::Length {
+                expected: HEADER_LEN,
+                found: bytes.len(),
+            })
+    };
+
+    let ndigits = i16::from_be_bytes(field(0)?).max(0) as usize;
+    let weight = i16::from_be_bytes(field(1)?);
+    let sign = u16::from_be_bytes(field(2)?);
+    let expected = HEADER_LEN + ndigits * 2;
+    if bytes.len() != expected {
+        return Err(PgNumericError::Length {
+            expected,
+            found: bytes.len(),
+        });
+    }
+
+    let negative = match sign {
+        POSITIVE => false,
+        NEGATIVE => true,
+        NAN => return Err(PgNumericError::NotFinite("NaN")),
+        POSITIVE_INFINITY => return Err(PgNumericError::NotFinite("Infinity")),
+        NEGATIVE_INFINITY => return Err(PgNumericError::NotFinite("-Infinity")),
+        sign => return Err(PgNumericError::Sign(sign)),
+    };
+
+    let groups = (0..ndigits)
+        .map(|index| {
+            let group = i16::from_be_bytes(field(4 + index)?);
+            if !(0..=9999).contains(&group) {
+                return Err(PgNumericError::Digit(group));
+            }
+            Ok(group)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Digit `index` is worth 10 000^(weight - index); write them out around the decimal point.
+    let mut integer = String::new();
+    let mut fraction = String::new();
+    for (index, group) in groups.iter().enumerate() {
+        let power = i32::from(weight) - index as i32;
+        let part = if power >= 0 {
+            &mut integer
+        } else {
+            &mut fraction
+        };
+        part.push_str(&format!("{group:04}"));
+    }
+    // Whole groups of zeros between the last digit and the decimal point, or between the decimal
+    // point and the first digit.
+    let trailing_groups = (i32::from(weight) + 1 - ndigits as i32).max(0) as usize;
+    integer.push_str(&"0".repeat(trailing_groups * DIGITS_PER_GROUP));
+    let leading_groups = (-i32::from(weight) - 1).max(0) as usize;
+    let fraction = "0".repeat(leading_groups * DIGITS_PER_GROUP) + &fraction;
+
+    let sign = if negative { "-" } else { "" };
+    let integer = match integer.trim_start_matches('0') {
+        "" => "0",
+        integer => integer,
+    };
+    let text = if fraction.is_empty() {
+        format!("{sign}{integer}")
+    } else {
+        format!("{sign}{integer}.{fraction}")
+    };
+
+    Ok(text.parse()?)
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use crate::domain::value_type::amount::Amount;
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, IsNull, ToSql, Type, accepts, to_sql_checked};
+    use std::error::Error;
+
+    impl<const DECIMALS: usize, const N: usize> ToSql for Amount<DECIMALS, N> {
+        fn to_sql(
+            &self,
+            _: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            out.extend_from_slice(&super::encode(self));
+            Ok(IsNull::No)
+        }
+
+        accepts!(NUMERIC);
+
+        to_sql_checked!();
+    }
+
+    impl<'a, const DECIMALS: usize, const N: usize> FromSql<'a> for Amount<DECIMALS, N> {
+        fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            Ok(super::decode(raw)?)
+        }
+
+        accepts!(NUMERIC);
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use crate::domain::value_type::amount::Amount;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+    use sqlx::{Decode, Encode, Type};
+
+    impl<const DECIMALS: usize, const N: usize> Type<Postgres> for Amount<DECIMALS, N> {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("NUMERIC")
+        }
+    }
+
+    impl<const DECIMALS: usize, const N: usize> Encode<'_, Postgres> for Amount<DECIMALS, N> {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            buf.extend_from_slice(&super::encode(self));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'r, const DECIMALS: usize, const N: usize> Decode<'r, Postgres> for Amount<DECIMALS, N> {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => Ok(super::decode(value.as_bytes()?)?),
+                PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PgNumericError, decode, encode};
+    use crate::domain::value_type::amount::{Amount, Amount256, ParseAmountError};
+    use rstest::rstest;
+
+    /// Header fields and digits as Postgres sends them, e.g. from `SELECT 12.30::numeric(10, 2)`.
+    fn numeric(ndigits: i16, weight: i16, sign: u16, dscale: u16, digits: &[i16]) -> Vec<u8> {
+        [ndigits, weight, sign as i16, dscale as i16]
+            .iter()
+            .chain(digits)
+            .flat_map(|field| field.to_be_bytes())
+            .collect()
+    }
+
+    #[rstest]
+    #[case("12.30", numeric(2, 0, 0x0000, 2, &[12, 3000]))]
+    #[case("-12.30", numeric(2, 0, 0x4000, 2, &[12, 3000]))]
+    #[case("0.00", numeric(0, 0, 0x0000, 2, &[]))]
+    #[case("0.05", numeric(1, -1, 0x0000, 2, &[500]))]
+    #[case("10000.00", numeric(1, 1, 0x0000, 2, &[1]))]
+    #[case("123456789.01", numeric(4, 2, 0x0000, 2, &[1, 2345, 6789, 100]))]
+    #[case("-92233720368547758.08", numeric(6, 4, 0x4000, 2, &[9, 2233, 7203, 6854, 7758, 800]))]
+    fn amount2(#[case] input: &str, #[case] bytes: Vec<u8>) {
+        let amount: Amount<2> = input.parse().unwrap();
+
+        assert_eq!(encode(&amount), bytes);
+        assert_eq!(decode::<2, 2>(&bytes), Ok(amount));
+    }
+
+    #[rstest]
+    #[case("0.00000001", numeric(1, -2, 0x0000, 8, &[1]))]
+    #[case("0.12345678", numeric(2, -1, 0x0000, 8, &[1234, 5678]))]
+    #[case("100000000.00000000", numeric(1, 2, 0x0000, 8, &[1]))]
+    fn amount8(#[case] input: &str, #[case] bytes: Vec<u8>) {
+        let amount: Amount<8> = input.parse().unwrap();
+
+        assert_eq!(encode(&amount), bytes);
+        assert_eq!(decode::<8, 2>(&bytes), Ok(amount));
+    }
+
+    #[test]
+    fn wide_amount() {
+        let wei: Amount256<18> = "123456789012345678901234567890.123456789012345678"
+            .parse()
+            .unwrap();
+
+        assert_eq!(decode::<18, 4>(&encode(&wei)), Ok(wei));
+    }
+
+    #[test]
+    fn decodes_wider_dscale() {
+        // `NUMERIC(12, 4)` holding 12.3400
+        let bytes = numeric(2, 0, 0x0000, 4, &[12, 3400]);
+
+        assert_eq!(decode::<2, 2>(&bytes), Ok("12.34".parse().unwrap()));
+        assert_eq!(decode::<2, 2>(&bytes).unwrap().to_string(), "12.34");
+    }
+
+    #[rstest]
+    #[case(numeric(2, 0, 0x0000, 3, &[12, 3450]), PgNumericError::Amount(ParseAmountError::Scale { input: "12.3450".to_string(), found: 3, expected: 2 }))]
+    #[case(numeric(0, 0, 0xC000, 0, &[]), PgNumericError::NotFinite("NaN"))]
+    #[case(numeric(0, 0, 0xF000, 0, &[]), PgNumericError::NotFinite("-Infinity"))]
+    #[case(numeric(0, 0, 0x1234, 0, &[]), PgNumericError::Sign(0x1234))]
+    #[case(numeric(1, 0, 0x0000, 0, &[10000]), PgNumericError::Digit(10000))]
+    #[case(numeric(2, 0, 0x0000, 0, &[1]), PgNumericError::Length { expected: 12, found: 10 })]
+    #[case(vec![0, 1, 0], PgNumericError::Length { expected: 8, found: 3 })]
+    fn decode_errors(#[case] bytes: Vec<u8>, #[case] expected: PgNumericError) {
+        assert_eq!(decode::<2, 2>(&bytes), Err(expected));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_types() {
+        use bytes::BytesMut;
+        use postgres_types::{FromSql, ToSql, Type};
+
+        let amount: Amount<2> = "-12.30".parse().unwrap();
+        let mut out = BytesMut::new();
+        amount.to_sql_checked(&Type::NUMERIC, &mut out).unwrap();
+
+        assert_eq!(out.as_ref(), encode(&amount));
+        assert_eq!(Amount::<2>::from_sql(&Type::NUMERIC, &out).unwrap(), amount);
+        assert!(!<Amount<2> as ToSql>::accepts(&Type::TEXT));
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn sqlx() {
+        use sqlx::Encode;
+        use sqlx::encode::IsNull;
+        use sqlx::postgres::{PgArgumentBuffer, Postgres};
+
+        let amount: Amount<2> = "-12.30".parse().unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        let is_null = Encode::<Postgres>::encode_by_ref(&amount, &mut buf).unwrap();
+
+        assert!(matches!(is_null, IsNull::No));
+
+        assert_eq!(buf.as_slice(), encode(&amount));
+    }
+}
@@ -0,0 +1,266 @@
+//! Protobuf messages for amounts, wire-compatible with
+//! [`google.type.Money`](https://github.com/googleapis/googleapis/blob/master/google/type/money.proto)
+//! and [`google.type.Decimal`](https://github.com/googleapis/googleapis/blob/master/google/type/decimal.proto).
+//!
+//! The messages are what `prost-build` generates for those definitions, written out so that building
+//! does not need `protoc`.
+
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+use crate::domain::value_type::currency::{Currency, ParseCurrencyError};
+use crate::domain::value_type::money;
+use crate::domain::value_type::scaled_amount::{MinorUnitsError, ScaledAmount};
+
+const NANOS_DECIMALS: usize = 9;
+const NANOS_PER_UNIT: i128 = 1_000_000_000;
+
+/// `google.type.Money`: whole `units` plus `nanos` (10^-9 units) of the same sign, e.g. -1.75 as
+/// units -1 and nanos -750 000 000.
+#[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+pub struct Money {
+    /// ISO 4217 currency code, e.g. "EUR".
+    #[prost(string, tag = "1")]
+    pub currency_code: String,
+    #[prost(int64, tag = "2")]
+    pub units: i64,
+    /// Within ±999 999 999, with the same sign as `units` when those are not zero.
+    #[prost(int32, tag = "3")]
+    pub nanos: i32,
+}
+
+/// `google.type.Decimal`: an amount as a decimal string, e.g. "12.345678901234", for scales finer
+/// than the nanos of [`Money`].
+#[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+pub struct DecimalValue {
+    #[prost(string, tag = "1")]
+    pub value: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ProtoMoneyError {
+    #[error(transparent)]
+    Currency(#[from] ParseCurrencyError),
+    #[error("nanos {0} out of range, expected at most ±999 999 999")]
+    NanosOutOfRange(i32),
+    #[error("units {units} and nanos {nanos} have different signs")]
+    SignMismatch { units: i64, nanos: i32 },
+    #[error("{value} needs more than {decimals} decimals")]
+    Precision { value: String, decimals: usize },
+    #[error("{0} does not fit")]
+    Overflow(String),
+}
+
+impl<const DECIMALS: usize> TryFrom<money::Money<DECIMALS>> for Money {
+    type Error = ProtoMoneyError;
+
+    /// Fails rather than rounding when the amount has digits beyond nanos; send those as a
+    /// [`DecimalValue`] instead.
+    fn try_from(value: money::Money<DECIMALS>) -> Result<Self, Self::Error> {
+        let amount = value.amount();
+        let overflow = || ProtoMoneyError::Overflow(amount.to_string());
+        let precision = |decimals| ProtoMoneyError::Precision {
+            value: amount.to_string(),
+            decimals,
+        };
+
+        let minor_units = ScaledAmount::<DECIMALS>::try_from(amount)
+            .map_err(|error| match error {
+                MinorUnitsError::Scale { .. } => precision(DECIMALS),
+                MinorUnitsError::Overflow(_) => overflow(),
+            })?
+            .minor_units();
+
+        let nanos = if DECIMALS <= NANOS_DECIMALS {
+            pow10(NANOS_DECIMALS - DECIMALS)
+                .and_then(|factor| minor_units.checked_mul(factor))
+                .ok_or_else(overflow)?
+        } else {
+            let factor = pow10(DECIMALS - NANOS_DECIMALS).ok_or_else(overflow)?;
+            if minor_units % factor != 0 {
+                return Err(precision(NANOS_DECIMALS));
+            }
+            minor_units / factor
+        };
+
+        Ok(Self {
+            currency_code: value.currency().code().to_string(),
+            units: i64::try_from(nanos / NANOS_PER_UNIT).map_err(|_| overflow())?,
+            // Truncating division leaves a remainder with the sign of `nanos`, so the signs match.
+            nanos: (nanos % NANOS_PER_UNIT) as i32,
+        })
+    }
+}
+
+impl<const DECIMALS: usize> TryFrom<Money> for money::Money<DECIMALS> {
+    type Error = ProtoMoneyError;
+
+    fn try_from(value: Money) -> Result<Self, Self::Error> {
+        let currency: Currency = value.currency_code.parse()?;
+
+        if value.nanos.unsigned_abs() >= NANOS_PER_UNIT as u32 {
+            return Err(ProtoMoneyError::NanosOutOfRange(value.nanos));
+        }
+        if value.units.signum() * i64::from(value.nanos.signum()) < 0 {
+            return Err(ProtoMoneyError::SignMismatch {
+                units: value.units,
+                nanos: value.nanos,
+            });
+        }
+
+        let nanos = i128::from(value.units) * NANOS_PER_UNIT + i128::from(value.nanos);
+        let display = || ScaledAmount::<NANOS_DECIMALS>::new_scaled_i128(nanos).to_string();
+
+        let minor_units = if DECIMALS <= NANOS_DECIMALS {
+            let factor = pow10(NANOS_DECIMALS - DECIMALS).expect("at most 10^9");
+            if nanos % factor != 0 {
+                return Err(ProtoMoneyError::Precision {
+                    value: display(),
+                    decimals: DECIMALS,
+                });
+            }
+            nanos / factor
+        } else {
+            pow10(DECIMALS - NANOS_DECIMALS)
+                .and_then(|factor| nanos.checked_mul(factor))
+                .ok_or_else(|| ProtoMoneyError::Overflow(display()))?
+        };
+
+        Ok(Self::new(
+            ScaledAmount::<DECIMALS>::new_scaled_i128(minor_units).into(),
+            currency,
+        ))
+    }
+}
+
+impl<const DECIMALS: usize, const N: usize> From<Amount<DECIMALS, N>> for DecimalValue {
+    fn from(value: Amount<DECIMALS, N>) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl<const DECIMALS: usize, const N: usize> TryFrom<DecimalValue> for Amount<DECIMALS, N> {
+    type Error = ParseAmountError;
+
+    fn try_from(value: DecimalValue) -> Result<Self, Self::Error> {
+        value.value.parse()
+    }
+}
+
+fn pow10(exponent: usize) -> Option<i128> {
+    10_i128.checked_pow(u32::try_from(exponent).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecimalValue, Money, ProtoMoneyError};
+    use crate::domain::value_type::amount::{Amount, Amount256, ParseAmountError};
+    use crate::domain::value_type::currency::Currency;
+    use crate::domain::value_type::money;
+    use prost::Message;
+    use rstest::rstest;
+
+    fn proto(currency_code: &str, units: i64, nanos: i32) -> Money {
+        Money {
+            currency_code: currency_code.to_string(),
+            units,
+            nanos,
+        }
+    }
+
+    fn money<const DECIMALS: usize>(amount: &str, currency: Currency) -> money::Money<DECIMALS> {
+        money::Money::new(amount.parse().unwrap(), currency)
+    }
+
+    #[rstest]
+    #[case("12.34", 12, 340_000_000)]
+    #[case("-1.75", -1, -750_000_000)]
+    #[case("-0.01", 0, -10_000_000)]
+    #[case("0.00", 0, 0)]
+    #[case("92233720368547758.07", 92_233_720_368_547_758, 70_000_000)]
+    fn round_trip_cents(#[case] amount: &str, #[case] units: i64, #[case] nanos: i32) {
+        let money = money::<2>(amount, Currency::EUR);
+        let message = Money::try_from(money).unwrap();
+
+        assert_eq!(message, proto("EUR", units, nanos));
+
+        let decoded = Money::decode(message.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(money::Money::<2>::try_from(decoded), Ok(money));
+    }
+
+    #[test]
+    fn round_trip_nanos() {
+        let money = money::<9>("-3.000000001", Currency::USD);
+        let message = Money::try_from(money).unwrap();
+
+        assert_eq!(message, proto("USD", -3, -1));
+        assert_eq!(money::Money::<9>::try_from(message), Ok(money));
+    }
+
+    #[test]
+    fn finer_scales() {
+        assert_eq!(
+            Money::try_from(money::<12>("1.500000000000", Currency::CHF)),
+            Ok(proto("CHF", 1, 500_000_000))
+        );
+        assert_eq!(
+            Money::try_from(money::<12>("1.500000000001", Currency::CHF)),
+            Err(ProtoMoneyError::Precision {
+                value: "1.500000000001".to_string(),
+                decimals: 9,
+            })
+        );
+        assert_eq!(
+            money::Money::<12>::try_from(proto("CHF", 1, 5)),
+            Ok(money::<12>("1.000000005000", Currency::CHF))
+        );
+    }
+
+    #[rstest]
+    #[case(proto("EUR", 1, 5), ProtoMoneyError::Precision { value: "1.000000005".to_string(), decimals: 2 })]
+    #[case(proto("EUR", 1, -5), ProtoMoneyError::SignMismatch { units: 1, nanos: -5 })]
+    #[case(proto("EUR", -1, 1_000_000_000), ProtoMoneyError::NanosOutOfRange(1_000_000_000))]
+    #[case(proto("eur", 1, 0), ProtoMoneyError::Currency("eur".parse::<Currency>().unwrap_err()))]
+    fn decode_errors(#[case] message: Money, #[case] expected: ProtoMoneyError) {
+        assert_eq!(money::Money::<2>::try_from(message), Err(expected));
+    }
+
+    #[test]
+    fn encode_errors() {
+        let extra_decimals = money::Money::new(Amount::<2>::new_f64(0.1), Currency::EUR);
+        assert!(matches!(
+            Money::try_from(extra_decimals),
+            Err(ProtoMoneyError::Precision { decimals: 2, .. })
+        ));
+
+        let too_large = money::<2>("92233720368547758080.00", Currency::EUR);
+        assert_eq!(
+            Money::try_from(too_large),
+            Err(ProtoMoneyError::Overflow(
+                "92233720368547758080.00".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn decimal_value() {
+        let wei: Amount256<18> = "123456789012345678901234567890.123456789012345678"
+            .parse()
+            .unwrap();
+        let message = DecimalValue::from(wei);
+
+        assert_eq!(
+            message.value,
+            "123456789012345678901234567890.123456789012345678"
+        );
+        let decoded = DecimalValue::decode(message.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(Amount256::<18>::try_from(decoded), Ok(wei));
+
+        assert!(matches!(
+            Amount::<2>::try_from(DecimalValue {
+                value: "0.001".to_string()
+            }),
+            Err(ParseAmountError::Scale { .. })
+        ));
+    }
+}
@@ -0,0 +1,326 @@
+//! JSON Schema (`schemars`) and OpenAPI (`utoipa`) descriptions of the value types, matching their
+//! serde formats:
+//!
+//! - `Amount<DECIMALS>`: a decimal string whose pattern allows at most `DECIMALS` decimals, named
+//!   e.g. `Amount2`.
+//! - `Currency`: a string of 3 uppercase letters.
+//! - `Money<DECIMALS>`: an object with a required `amount` and `currency`, named e.g. `Money2`.
+//! - `Uid<T>`: a `uuid`-format string whose description names `T`, named e.g. `Uid_for_Account`
+//!   (schemars) or `Uid_Account` (utoipa), following each library's naming of generics.
+
+use crate::domain::value_type::scaled_amount::ScaledAmount;
+
+fn amount_name<const DECIMALS: usize>() -> String {
+    format!("Amount{DECIMALS}")
+}
+
+/// e.g. `^-?[0-9]+(\.[0-9]{1,2})?$` for 2 decimals.
+fn amount_pattern<const DECIMALS: usize>() -> String {
+    match DECIMALS {
+        0 => r"^-?[0-9]+$".to_string(),
+        decimals => format!(r"^-?[0-9]+(\.[0-9]{{1,{decimals}}})?$"),
+    }
+}
+
+fn amount_description<const DECIMALS: usize>() -> String {
+    format!("A decimal amount with at most {DECIMALS} decimals, as a string to keep it exact.")
+}
+
+fn amount_example<const DECIMALS: usize>() -> String {
+    ScaledAmount::<DECIMALS>::new_scaled_i32(1234).to_string()
+}
+
+const CURRENCY_PATTERN: &str = "^[A-Z]{3}$";
+const CURRENCY_DESCRIPTION: &str = "An ISO 4217 alphabetic currency code.";
+
+fn money_name<const DECIMALS: usize>() -> String {
+    format!("Money{DECIMALS}")
+}
+
+fn money_description<const DECIMALS: usize>() -> String {
+    format!("An amount with at most {DECIMALS} decimals in a currency.")
+}
+
+/// The type name of `T` without its module path or generics, e.g. `Account`.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn uid_description<T>() -> String {
+    format!("{} identifier (UUID).", type_name::<T>())
+}
+
+#[cfg(feature = "schemars")]
+mod schemars {
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use crate::domain::value_type::money::Money;
+    use crate::domain::value_type::uid::Uid;
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+    use std::borrow::Cow;
+
+    impl<const DECIMALS: usize, const N: usize> JsonSchema for Amount<DECIMALS, N> {
+        fn schema_name() -> Cow<'static, str> {
+            super::amount_name::<DECIMALS>().into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "pattern": super::amount_pattern::<DECIMALS>(),
+                "description": super::amount_description::<DECIMALS>(),
+                "examples": [super::amount_example::<DECIMALS>()],
+            })
+        }
+    }
+
+    impl JsonSchema for Currency {
+        fn schema_name() -> Cow<'static, str> {
+            "Currency".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "pattern": super::CURRENCY_PATTERN,
+                "description": super::CURRENCY_DESCRIPTION,
+                "examples": ["EUR"],
+            })
+        }
+    }
+
+    impl<const DECIMALS: usize> JsonSchema for Money<DECIMALS> {
+        fn schema_name() -> Cow<'static, str> {
+            super::money_name::<DECIMALS>().into()
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "object",
+                "properties": {
+                    "amount": generator.subschema_for::<Amount<DECIMALS>>(),
+                    "currency": generator.subschema_for::<Currency>(),
+                },
+                "required": ["amount", "currency"],
+                "description": super::money_description::<DECIMALS>(),
+            })
+        }
+    }
+
+    impl<T> JsonSchema for Uid<T> {
+        fn schema_name() -> Cow<'static, str> {
+            format!("Uid_for_{}", super::type_name::<T>()).into()
+        }
+
+        fn schema_id() -> Cow<'static, str> {
+            format!("Uid<{}>", std::any::type_name::<T>()).into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "format": "uuid",
+                "description": super::uid_description::<T>(),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa {
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use crate::domain::value_type::money::Money;
+    use crate::domain::value_type::uid::Uid;
+    use std::borrow::Cow;
+    use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+    use utoipa::openapi::{Ref, RefOr};
+    use utoipa::{PartialSchema, ToSchema};
+
+    impl<const DECIMALS: usize, const N: usize> PartialSchema for Amount<DECIMALS, N> {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .pattern(Some(super::amount_pattern::<DECIMALS>()))
+                .description(Some(super::amount_description::<DECIMALS>()))
+                .examples([super::amount_example::<DECIMALS>()])
+                .into()
+        }
+    }
+
+    impl<const DECIMALS: usize, const N: usize> ToSchema for Amount<DECIMALS, N> {
+        fn name() -> Cow<'static, str> {
+            super::amount_name::<DECIMALS>().into()
+        }
+    }
+
+    impl PartialSchema for Currency {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .pattern(Some(super::CURRENCY_PATTERN))
+                .description(Some(super::CURRENCY_DESCRIPTION))
+                .examples(["EUR"])
+                .into()
+        }
+    }
+
+    impl ToSchema for Currency {}
+
+    impl<const DECIMALS: usize> PartialSchema for Money<DECIMALS> {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(Type::Object)
+                .property(
+                    "amount",
+                    Ref::from_schema_name(<Amount<DECIMALS> as ToSchema>::name()),
+                )
+                .required("amount")
+                .property("currency", Ref::from_schema_name(Currency::name()))
+                .required("currency")
+                .description(Some(super::money_description::<DECIMALS>()))
+                .into()
+        }
+    }
+
+    impl<const DECIMALS: usize> ToSchema for Money<DECIMALS> {
+        fn name() -> Cow<'static, str> {
+            super::money_name::<DECIMALS>().into()
+        }
+
+        fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+            schemas.push((
+                <Amount<DECIMALS> as ToSchema>::name().into(),
+                <Amount<DECIMALS> as PartialSchema>::schema(),
+            ));
+            schemas.push((Currency::name().into(), Currency::schema()));
+        }
+    }
+
+    impl<T> PartialSchema for Uid<T> {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::Custom("uuid".to_string())))
+                .description(Some(super::uid_description::<T>()))
+                .into()
+        }
+    }
+
+    impl<T> ToSchema for Uid<T> {
+        fn name() -> Cow<'static, str> {
+            format!("Uid_{}", super::type_name::<T>()).into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{amount_example, amount_pattern, type_name};
+    use rstest::rstest;
+
+    struct Account;
+
+    #[test]
+    fn names() {
+        assert_eq!(type_name::<Account>(), "Account");
+        assert_eq!(type_name::<Vec<Account>>(), "Vec");
+    }
+
+    #[rstest]
+    #[case(0, r"^-?[0-9]+$", "1234")]
+    #[case(2, r"^-?[0-9]+(\.[0-9]{1,2})?$", "12.34")]
+    #[case(4, r"^-?[0-9]+(\.[0-9]{1,4})?$", "0.1234")]
+    fn amount_patterns(#[case] decimals: usize, #[case] pattern: &str, #[case] example: &str) {
+        let (actual_pattern, actual_example) = match decimals {
+            0 => (amount_pattern::<0>(), amount_example::<0>()),
+            2 => (amount_pattern::<2>(), amount_example::<2>()),
+            _ => (amount_pattern::<4>(), amount_example::<4>()),
+        };
+
+        assert_eq!(actual_pattern, pattern);
+        assert_eq!(actual_example, example);
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn schemars() {
+        use crate::domain::value_type::amount::Amount;
+        use crate::domain::value_type::money::Money;
+        use crate::domain::value_type::uid::Uid;
+        use serde_json::json;
+
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Payment {
+            id: Uid<Account>,
+            price: Money<2>,
+            fee: Amount<4>,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Payment)).unwrap();
+        let definitions = &schema["$defs"];
+
+        assert_eq!(
+            schema["properties"]["price"],
+            json!({"$ref": "#/$defs/Money2"})
+        );
+        assert_eq!(
+            definitions["Amount2"]["pattern"],
+            json!(r"^-?[0-9]+(\.[0-9]{1,2})?$")
+        );
+        assert_eq!(
+            definitions["Amount4"]["pattern"],
+            json!(r"^-?[0-9]+(\.[0-9]{1,4})?$")
+        );
+        assert_eq!(
+            definitions["Money2"]["required"],
+            json!(["amount", "currency"])
+        );
+        assert_eq!(
+            definitions["Money2"]["properties"]["currency"],
+            json!({"$ref": "#/$defs/Currency"})
+        );
+        assert_eq!(
+            definitions["Uid_for_Account"],
+            json!({
+                "type": "string",
+                "format": "uuid",
+                "description": "Account identifier (UUID).",
+            })
+        );
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn utoipa() {
+        use crate::domain::value_type::currency::Currency;
+        use crate::domain::value_type::money::Money;
+        use crate::domain::value_type::uid::Uid;
+        use serde_json::json;
+        use utoipa::{PartialSchema, ToSchema};
+
+        let money = serde_json::to_value(Money::<2>::schema()).unwrap();
+        assert_eq!(money["required"], json!(["amount", "currency"]));
+        assert_eq!(
+            money["properties"]["amount"],
+            json!({"$ref": "#/components/schemas/Amount2"})
+        );
+
+        let mut schemas = Vec::new();
+        <Money<2> as ToSchema>::schemas(&mut schemas);
+        let names: Vec<&str> = schemas.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Amount2", "Currency"]);
+        assert_eq!(
+            serde_json::to_value(Currency::schema()).unwrap()["pattern"],
+            json!("^[A-Z]{3}$")
+        );
+
+        let uid = serde_json::to_value(Uid::<Account>::schema()).unwrap();
+        assert_eq!(uid["format"], json!("uuid"));
+        assert_eq!(uid["description"], json!("Account identifier (UUID)."));
+        assert_eq!(Uid::<Account>::name(), "Uid_Account");
+    }
+}
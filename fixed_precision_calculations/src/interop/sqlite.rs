@@ -0,0 +1,288 @@
+//! `rusqlite` storage for [`Amount`] and [`Uid`]. SQLite has no decimal type, so:
+//!
+//! - [`Amount`] is written as INTEGER minor units, e.g. 1234 for 12.34, and [`AmountText`] as TEXT
+//!   with exactly `DECIMALS` decimals, e.g. "12.30". Store the latter in TEXT columns: SQLite converts
+//!   it into a REAL in columns with NUMERIC affinity, which includes `ANY` in non-STRICT tables.
+//! - [`Uid`] is written as a 16-byte BLOB, and [`UidText`] as canonical TEXT.
+//!
+//! Either representation reads back into both types. Reading never rounds: TEXT with another number
+//! of decimals, a blob of the wrong size or a malformed uuid fails with a [`FromSqlError`].
+
+use crate::domain::value_type::amount::{Amount, ParseAmountError};
+use crate::domain::value_type::packed_amount::PackedAmount;
+use crate::domain::value_type::scaled_amount::ScaledAmount;
+use crate::domain::value_type::uid::Uid;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+const UUID_LEN: usize = 16;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum SqliteValueError {
+    #[error("'{value}' has {found} decimals, expected exactly {expected}")]
+    Scale {
+        value: String,
+        found: usize,
+        expected: usize,
+    },
+    #[error(transparent)]
+    Amount(#[from] ParseAmountError),
+    #[error(transparent)]
+    Uuid(#[from] uuid::Error),
+}
+
+/// An [`Amount`] written as TEXT with exactly `DECIMALS` decimals, e.g. "12.30".
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AmountText<const DECIMALS: usize>(pub Amount<DECIMALS>);
+
+/// A [`Uid`] written as canonical TEXT, e.g. "67e55044-10b1-426f-9247-bb680e5fe0c8".
+pub struct UidText<T>(pub Uid<T>);
+
+impl<T> Clone for UidText<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UidText<T> {}
+
+impl<T> std::fmt::Debug for UidText<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("UidText").field(&self.0).finish()
+    }
+}
+
+impl<const DECIMALS: usize> ToSql for Amount<DECIMALS> {
+    /// Fails if the amount holds more than `DECIMALS` decimals or does not fit an [`i64`].
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let packed = PackedAmount::try_from(*self)
+            .map_err(|error| rusqlite::Error::ToSqlConversionFailure(Box::new(error)))?;
+        Ok(ToSqlOutput::from(packed.minor_units()))
+    }
+}
+
+impl<const DECIMALS: usize> FromSql for Amount<DECIMALS> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(minor_units) => Ok(Self::new_scaled_i64(minor_units)),
+            ValueRef::Text(_) => AmountText::column_result(value).map(|amount| amount.0),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl<const DECIMALS: usize> ToSql for AmountText<DECIMALS> {
+    /// Fails if the amount holds more than `DECIMALS` decimals.
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let scaled = ScaledAmount::try_from(self.0)
+            .map_err(|error| rusqlite::Error::ToSqlConversionFailure(Box::new(error)))?;
+        Ok(ToSqlOutput::from(scaled.to_string()))
+    }
+}
+
+impl<const DECIMALS: usize> FromSql for AmountText<DECIMALS> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let text = value.as_str()?;
+        let found = text
+            .split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len());
+        if found != DECIMALS {
+            return Err(other(SqliteValueError::Scale {
+                value: text.to_string(),
+                found,
+                expected: DECIMALS,
+            }));
+        }
+
+        text.parse()
+            .map(Self)
+            .map_err(|error| other(SqliteValueError::Amount(error)))
+    }
+}
+
+impl<T> ToSql for Uid<T> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(
+            uuid::Uuid::from(*self).as_bytes().to_vec(),
+        ))
+    }
+}
+
+impl<T> FromSql for Uid<T> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Blob(bytes) => {
+                let bytes: [u8; UUID_LEN] =
+                    bytes
+                        .try_into()
+                        .map_err(|_| FromSqlError::InvalidBlobSize {
+                            expected_size: UUID_LEN,
+                            blob_size: bytes.len(),
+                        })?;
+                Ok(uuid::Uuid::from_bytes(bytes).into())
+            }
+            ValueRef::Text(_) => UidText::column_result(value).map(|uid| uid.0),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl<T> ToSql for UidText<T> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0.to_string()))
+    }
+}
+
+impl<T> FromSql for UidText<T> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value
+            .as_str()?
+            .parse()
+            .map(Self)
+            .map_err(|error| other(SqliteValueError::Uuid(error)))
+    }
+}
+
+fn other(error: SqliteValueError) -> FromSqlError {
+    FromSqlError::Other(Box::new(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AmountText, SqliteValueError, UidText};
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::uid::Uid;
+    use rstest::rstest;
+    use rusqlite::types::FromSqlError;
+    use rusqlite::{Connection, params};
+
+    struct Account;
+
+    fn connection() -> Connection {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch("CREATE TABLE accounts (id ANY, balance ANY) STRICT")
+            .unwrap();
+        connection
+    }
+
+    fn read<V: rusqlite::types::FromSql>(
+        connection: &Connection,
+        column: &str,
+    ) -> rusqlite::Result<V> {
+        connection.query_row(&format!("SELECT {column} FROM accounts"), [], |row| {
+            row.get(0)
+        })
+    }
+
+    fn source(error: rusqlite::Error) -> SqliteValueError {
+        match error {
+            rusqlite::Error::FromSqlConversionFailure(_, _, source) => {
+                *source.downcast::<SqliteValueError>().unwrap()
+            }
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    #[test]
+    fn integer_and_blob() {
+        let connection = connection();
+        let id = Uid::<Account>::new_v4();
+        let balance: Amount<2> = "-12.34".parse().unwrap();
+        connection
+            .execute("INSERT INTO accounts VALUES (?1, ?2)", params![id, balance])
+            .unwrap();
+
+        let (kind, minor_units): (String, i64) = connection
+            .query_row("SELECT typeof(id), balance FROM accounts", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!((kind.as_str(), minor_units), ("blob", -1234));
+
+        assert_eq!(read::<Uid<Account>>(&connection, "id").unwrap(), id);
+        assert_eq!(read::<Amount<2>>(&connection, "balance").unwrap(), balance);
+    }
+
+    #[test]
+    fn text() {
+        let connection = connection();
+        let id = Uid::<Account>::new_v4();
+        let balance: Amount<2> = "12.3".parse().unwrap();
+        connection
+            .execute(
+                "INSERT INTO accounts VALUES (?1, ?2)",
+                params![UidText(id), AmountText(balance)],
+            )
+            .unwrap();
+
+        let (id_text, balance_text): (String, String) = connection
+            .query_row("SELECT id, balance FROM accounts", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(id_text, id.to_string());
+        assert_eq!(balance_text, "12.30");
+
+        assert_eq!(read::<UidText<Account>>(&connection, "id").unwrap().0, id);
+        assert_eq!(read::<Uid<Account>>(&connection, "id").unwrap(), id);
+        assert_eq!(read::<Amount<2>>(&connection, "balance").unwrap(), balance);
+        assert!(read::<AmountText<2>>(&connection, "balance").is_ok());
+    }
+
+    #[rstest]
+    #[case("'12.345'", SqliteValueError::Scale { value: "12.345".to_string(), found: 3, expected: 2 })]
+    #[case("'12.3'", SqliteValueError::Scale { value: "12.3".to_string(), found: 1, expected: 2 })]
+    #[case("'1x.00'", SqliteValueError::Amount(crate::domain::value_type::amount::ParseAmountError::Invalid("1x.00".to_string())))]
+    fn wrong_scale(#[case] literal: &str, #[case] expected: SqliteValueError) {
+        let connection = connection();
+        connection
+            .execute(
+                &format!("INSERT INTO accounts VALUES (NULL, {literal})"),
+                [],
+            )
+            .unwrap();
+
+        assert_eq!(
+            source(read::<Amount<2>>(&connection, "balance").unwrap_err()),
+            expected
+        );
+    }
+
+    #[test]
+    fn malformed_uuid() {
+        let connection = connection();
+        connection
+            .execute("INSERT INTO accounts VALUES ('not-a-uuid', x'0102')", [])
+            .unwrap();
+
+        assert!(matches!(
+            source(read::<Uid<Account>>(&connection, "id").unwrap_err()),
+            SqliteValueError::Uuid(_)
+        ));
+        assert!(matches!(
+            read::<Uid<Account>>(&connection, "balance"),
+            Err(rusqlite::Error::FromSqlConversionFailure(_, _, source))
+                if matches!(
+                    source.downcast_ref::<FromSqlError>(),
+                    Some(FromSqlError::InvalidBlobSize { expected_size: 16, blob_size: 2 })
+                )
+        ));
+        assert!(matches!(
+            read::<Amount<2>>(&connection, "balance"),
+            Err(rusqlite::Error::InvalidColumnType(..))
+        ));
+    }
+
+    #[test]
+    fn unrepresentable_amount() {
+        let connection = connection();
+
+        assert!(matches!(
+            connection.execute(
+                "INSERT INTO accounts VALUES (NULL, ?1)",
+                [Amount::<2>::new_f64(0.1)],
+            ),
+            Err(rusqlite::Error::ToSqlConversionFailure(_))
+        ));
+    }
+}
@@ -18,16 +18,72 @@
 #![warn(unused_imports)]
 #![allow(dead_code)]
 
-// NOTE: disabled
-// pub mod domain {
-//     pub mod value_type {
-//         pub mod amount;
-//     }
-// }
+pub mod domain {
+    pub mod value_type {
+        pub mod amount;
+        pub mod currency;
+        pub mod monetary_amount;
+        pub mod money;
+        pub mod packed_amount;
+        pub mod percentage;
+        pub mod rounding;
+        pub mod scaled_amount;
+        pub mod uid;
+    }
+    pub mod aggregate;
+    pub mod amount_vec;
+    pub mod currency_registry;
+    pub mod exchange_rates;
+    pub mod money_bag;
+    pub mod transaction;
+}
+
+pub mod analysis {
+    pub mod precision_drift;
+}
+
+pub mod calculations {
+    #[cfg(feature = "rayon")]
+    pub mod parallel;
+    pub mod statistics;
+    pub mod tax_brackets;
+}
+
+pub mod ingest {
+    #[cfg(feature = "camt")]
+    pub mod camt053;
+    pub mod csv_reader;
+    pub mod import;
+    pub mod mt940;
+    pub mod ofx;
+    pub mod qif;
+    pub mod statement;
+    #[cfg(test)]
+    pub(crate) mod test_support;
+}
+pub mod export {
+    pub mod pain001;
+}
+pub mod interop {
+    #[cfg(feature = "arrow")]
+    pub mod arrow;
+    #[cfg(feature = "graphql")]
+    pub mod graphql;
+    pub mod postgres_numeric;
+    #[cfg(feature = "protobuf")]
+    pub mod protobuf;
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub mod schema;
+    #[cfg(feature = "sqlite")]
+    pub mod sqlite;
+}
 
 pub mod utility {
     pub mod generate_data {
+        pub mod edge_case_generator;
         pub mod generate_monetary_values;
+        pub mod monetary_value_generator;
+        pub mod transaction_generator;
     }
 }
 
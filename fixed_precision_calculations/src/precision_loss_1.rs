@@ -10,28 +10,14 @@ static DATA: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
 
 pub mod amount;
 
-fn get_total_d128(data: &[&'static str]) -> D128 {
-    let mut accumulator = D128::from_f64(0.0);
-
-    data.iter().for_each(|s| {
-        let value: f64 = s.parse().unwrap();
-
-        accumulator = accumulator.add(D128::from_f64(value));
-    });
-
-    accumulator
+pub(crate) fn get_total_d128(data: &[&str]) -> D128 {
+    data.iter()
+        .map(|s| D128::from_f64(s.parse().unwrap()))
+        .sum()
 }
 
 fn get_total_f64(data: &[&'static str]) -> f64 {
-    let mut accumulator = 0.0;
-
-    data.iter().for_each(|s| {
-        let value: f64 = s.parse().unwrap();
-
-        accumulator += value;
-    });
-
-    accumulator
+    data.iter().map(|s| s.parse::<f64>().unwrap()).sum()
 }
 
 #[cfg(test)]
@@ -49,6 +35,7 @@ mod tests {
         assert_eq!(r_d128.round(20).to_string(), format!("{:.20}", r_f64));
 
         // NOTE: is this a sufficient test to show f64 precision loss? What's a better way to illustrate this, i.e. a larger dataset?
+        // See `analysis::precision_drift` and `cargo run --release --bin precision_drift -- 1000000` for the larger dataset.
         //
         // test precision_loss_1::tests::demo_f64_precision_loss ...
         //     thread 'precision_loss_1::tests::demo_f64_precision_loss' panicked at src/precision_loss_1:50:9:
@@ -0,0 +1,367 @@
+use crate::domain::value_type::amount::Amount;
+use fastnum::decimal::{Context, Sign};
+use fastnum::{D128, U128};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+
+/// Values known to expose decimal bugs, e.g. in rounding or in conversions.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EdgeCaseKind {
+    /// Exactly halfway between two amounts with `DECIMALS` decimals, e.g. 2.675 for cents.
+    RoundingTie,
+    /// At or one minor unit past the bounds of `From<Amount> for i32` and `From<Amount> for i64`.
+    ConversionBoundary,
+    /// As many significant digits as a [`D128`] holds.
+    MaxPrecision,
+    /// Zero with a negative sign, e.g. from `-0.0_f64` or "-0.00".
+    NegativeZero,
+    /// Exact binary expansion of an `f64` that looks short in decimal, e.g. 56098.9.
+    LongBinaryExpansion,
+    /// Far above or below everyday amounts, up to the limits of [`D128`].
+    ExtremeMagnitude,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct EdgeCase<const DECIMALS: usize> {
+    pub kind: EdgeCaseKind,
+    pub amount: Amount<DECIMALS>,
+}
+
+/// Generates decimal edge cases for `Amount<DECIMALS>`.
+///
+/// [`EdgeCaseGenerator::cases`] always starts with the fixed [`EdgeCaseGenerator::catalogue`], so
+/// that every known edge case is exercised on every run, and then continues with random instances of
+/// each [`EdgeCaseKind`].
+///
+/// ```
+/// use fixed_precision_calculations::utility::generate_data::edge_case_generator::EdgeCaseGenerator;
+///
+/// for case in EdgeCaseGenerator::<2>::new(42).cases().take(500) {
+///     let _ = case.amount.to_minor_units();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EdgeCaseGenerator<const DECIMALS: usize> {
+    rng: StdRng,
+}
+
+/// `f64` values whose shortest representation looks exact, but whose binary value is not.
+const LONG_BINARY_EXPANSIONS: [f64; 10] = [
+    0.1, 0.2, 0.3, 0.07, 1.005, 2.675, 4.35, 8.675, 56098.9, 1234567.89,
+];
+
+impl EdgeCaseKind {
+    pub const ALL: [Self; 6] = [
+        Self::RoundingTie,
+        Self::ConversionBoundary,
+        Self::MaxPrecision,
+        Self::NegativeZero,
+        Self::LongBinaryExpansion,
+        Self::ExtremeMagnitude,
+    ];
+}
+
+impl<const DECIMALS: usize> EdgeCaseGenerator<DECIMALS> {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Every fixed edge case, grouped by kind.
+    pub fn catalogue() -> Vec<EdgeCase<DECIMALS>> {
+        let ties = [0, 1, 2, 100, 267, 999_999]
+            .into_iter()
+            .flat_map(|minor_units| [tie(minor_units), -tie(minor_units)]);
+
+        let boundaries = [i32::MIN, i32::MAX]
+            .into_iter()
+            .map(i64::from)
+            .chain([i64::MIN, i64::MAX])
+            .flat_map(|bound| {
+                let bound = D128::from_i64(bound);
+                [bound, bound - D128::ONE, bound + D128::ONE]
+            })
+            .map(scaled);
+
+        let max_precision = [
+            D128::ONE / D128::from_i32(3),
+            -(D128::TWO / D128::from_i32(3)),
+            D128::TWO.sqrt(),
+            // All 39 digits of the coefficient, with `DECIMALS` of them after the point.
+            D128::from_parts(
+                U128::MAX,
+                -(DECIMALS as i32),
+                Sign::Plus,
+                Context::default(),
+            ),
+        ]
+        .map(Amount::from);
+
+        let negative_zeros = [
+            D128::ZERO.neg(),
+            D128::from_f64(-0.0),
+            "-0.00".parse().expect("valid decimal"),
+        ]
+        .map(Amount::from);
+
+        let long_binary_expansions = LONG_BINARY_EXPANSIONS
+            .into_iter()
+            .flat_map(|value| [value, -value])
+            .chain([0.1 + 0.2])
+            .map(Amount::new_f64);
+
+        let extreme_magnitudes = [
+            D128::MAX,
+            D128::MIN,
+            D128::MIN_POSITIVE,
+            D128::MIN_POSITIVE.neg(),
+            D128::TEN.powi(30),
+            D128::TEN.powi(-30),
+            // One minor unit.
+            scaled::<DECIMALS>(D128::ONE).raw(),
+        ]
+        .map(Amount::from);
+
+        let kinds = [
+            (EdgeCaseKind::RoundingTie, ties.collect::<Vec<_>>()),
+            (EdgeCaseKind::ConversionBoundary, boundaries.collect()),
+            (EdgeCaseKind::MaxPrecision, max_precision.to_vec()),
+            (EdgeCaseKind::NegativeZero, negative_zeros.to_vec()),
+            (
+                EdgeCaseKind::LongBinaryExpansion,
+                long_binary_expansions.collect(),
+            ),
+            (EdgeCaseKind::ExtremeMagnitude, extreme_magnitudes.to_vec()),
+        ];
+
+        kinds
+            .into_iter()
+            .flat_map(|(kind, amounts)| {
+                amounts
+                    .into_iter()
+                    .map(move |amount| EdgeCase { kind, amount })
+            })
+            .collect()
+    }
+
+    /// A random edge case of the given kind.
+    pub fn next_case(&mut self, kind: EdgeCaseKind) -> EdgeCase<DECIMALS> {
+        let sign = if self.rng.random_bool(0.5) {
+            D128::ONE
+        } else {
+            D128::ONE.neg()
+        };
+
+        let amount = match kind {
+            EdgeCaseKind::RoundingTie => tie(self.rng.random_range(0..1_000_000_000)),
+            EdgeCaseKind::ConversionBoundary => {
+                let bound = *[i32::MIN as i64, i32::MAX as i64, i64::MIN, i64::MAX]
+                    .choose(&mut self.rng)
+                    .expect("bounds are not empty");
+                let offset = self.rng.random_range(-2..=2);
+
+                scaled(D128::from_i64(bound) + D128::from_i32(offset))
+            }
+            EdgeCaseKind::MaxPrecision => {
+                // 38 digits always fit the 128-bit coefficient.
+                let digits = self.random_digits(38);
+                let point = self.rng.random_range(1..digits.len());
+
+                Amount::from(decimal(&format!(
+                    "{}.{}",
+                    &digits[..point],
+                    &digits[point..]
+                )))
+            }
+            EdgeCaseKind::NegativeZero => {
+                let decimals = self.rng.random_range(0..=DECIMALS as i32 + 2);
+
+                Amount::from(D128::from_parts(
+                    U128::ZERO,
+                    -decimals,
+                    Sign::Minus,
+                    Context::default(),
+                ))
+            }
+            EdgeCaseKind::LongBinaryExpansion => {
+                // Short decimals such as 123.45 are rarely exact in binary.
+                let minor_units = self.rng.random_range(1..100_000_000_i64);
+
+                Amount::new_f64(minor_units as f64 / 100.0)
+            }
+            EdgeCaseKind::ExtremeMagnitude => {
+                let exponent = self.rng.random_range(20..=36);
+                let exponent = if self.rng.random_bool(0.5) {
+                    exponent
+                } else {
+                    -exponent
+                };
+
+                Amount::from(
+                    D128::from_i32(self.rng.random_range(1..10)) * D128::TEN.powi(exponent),
+                )
+            }
+        };
+
+        let amount = if kind == EdgeCaseKind::NegativeZero {
+            amount
+        } else {
+            Amount::from(amount.raw() * sign)
+        };
+
+        EdgeCase { kind, amount }
+    }
+
+    /// A random edge case of a random kind.
+    pub fn next_random(&mut self) -> EdgeCase<DECIMALS> {
+        let kind = *EdgeCaseKind::ALL
+            .choose(&mut self.rng)
+            .expect("kinds are not empty");
+
+        self.next_case(kind)
+    }
+
+    /// The whole catalogue, followed by an endless stream of random edge cases.
+    pub fn cases(&mut self) -> impl Iterator<Item = EdgeCase<DECIMALS>> + '_ {
+        Self::catalogue()
+            .into_iter()
+            .chain(std::iter::repeat_with(|| self.next_random()))
+    }
+
+    fn random_digits(&mut self, count: usize) -> String {
+        let first = self.rng.random_range(1..=9_u8);
+
+        std::iter::once(first)
+            .chain((1..count).map(|_| self.rng.random_range(0..=9)))
+            .map(|digit| char::from(b'0' + digit))
+            .collect()
+    }
+}
+
+/// `minor_units` followed by a 5 in the first decimal past `DECIMALS`, e.g. 267 → 2.675 for cents.
+fn tie<const DECIMALS: usize>(minor_units: i64) -> Amount<DECIMALS> {
+    Amount::from(
+        (D128::from_i64(minor_units) * D128::TEN + D128::from_i32(5))
+            / D128::TEN.powi(DECIMALS as i32 + 1),
+    )
+}
+
+/// `minor_units` as an amount, e.g. 1234 → 12.34 for cents.
+fn scaled<const DECIMALS: usize>(minor_units: D128) -> Amount<DECIMALS> {
+    Amount::from(minor_units / D128::TEN.powi(DECIMALS as i32))
+}
+
+fn decimal(value: &str) -> D128 {
+    value.parse().expect("generated decimals are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EdgeCase, EdgeCaseGenerator, EdgeCaseKind};
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::rounding::RoundingMode;
+    use rstest::rstest;
+
+    fn cases(kind: EdgeCaseKind) -> impl Iterator<Item = Amount<2>> {
+        let mut generator = EdgeCaseGenerator::<2>::new(1);
+        let random: Vec<EdgeCase<2>> = (0..200).map(|_| generator.next_case(kind)).collect();
+
+        EdgeCaseGenerator::<2>::catalogue()
+            .into_iter()
+            .filter(move |case| case.kind == kind)
+            .chain(random)
+            .map(|case| case.amount)
+    }
+
+    #[test]
+    fn catalogue_covers_every_kind() {
+        let catalogue = EdgeCaseGenerator::<2>::catalogue();
+
+        for kind in EdgeCaseKind::ALL {
+            assert!(catalogue.iter().any(|case| case.kind == kind), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn cases_start_with_catalogue_and_are_reproducible() {
+        let catalogue = EdgeCaseGenerator::<2>::catalogue();
+        let first: Vec<_> = EdgeCaseGenerator::<2>::new(9).cases().take(500).collect();
+        let second: Vec<_> = EdgeCaseGenerator::<2>::new(9).cases().take(500).collect();
+
+        assert_eq!(first[..catalogue.len()], catalogue[..]);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn rounding_ties_are_halfway() {
+        for amount in cases(EdgeCaseKind::RoundingTie) {
+            let (up, down) = (
+                amount.round(RoundingMode::HalfUp),
+                amount.round(RoundingMode::HalfDown),
+            );
+
+            assert_eq!(amount.decimals(), 3, "{amount}");
+            assert_eq!((up - down).abs(), Amount::new_scaled_i32(1), "{amount}");
+        }
+    }
+
+    #[test]
+    fn conversion_boundaries_straddle_integer_bounds() {
+        let catalogue: Vec<Option<i64>> = cases(EdgeCaseKind::ConversionBoundary)
+            .take(12)
+            .map(|amount| amount.to_minor_units())
+            .collect();
+
+        assert!(catalogue.contains(&Some(i32::MAX as i64)));
+        assert!(catalogue.contains(&Some(i32::MAX as i64 + 1)));
+        assert!(catalogue.contains(&Some(i64::MIN)));
+        assert!(catalogue.contains(&None));
+
+        for amount in cases(EdgeCaseKind::ConversionBoundary) {
+            if let Some(minor_units) = amount.to_minor_units() {
+                assert_eq!(Amount::new_scaled_i64(minor_units), amount);
+            }
+        }
+    }
+
+    #[test]
+    fn negative_zeros() {
+        for amount in cases(EdgeCaseKind::NegativeZero) {
+            assert!(amount.is_zero(), "{amount}");
+            assert!(amount.raw().is_sign_negative(), "{amount}");
+        }
+    }
+
+    #[test]
+    fn long_binary_expansions_exceed_declared_scale() {
+        let catalogue = EdgeCaseGenerator::<2>::catalogue();
+
+        for case in catalogue
+            .iter()
+            .filter(|case| case.kind == EdgeCaseKind::LongBinaryExpansion)
+        {
+            assert!(case.amount.decimals() > 2, "{}", case.amount);
+            assert_eq!(case.amount.to_minor_units(), None);
+        }
+    }
+
+    #[rstest]
+    #[case(EdgeCaseKind::MaxPrecision)]
+    #[case(EdgeCaseKind::ExtremeMagnitude)]
+    fn checked_arithmetic_does_not_panic(#[case] kind: EdgeCaseKind) {
+        for amount in cases(kind) {
+            let _ = amount.checked_add(amount);
+            let _ = amount.checked_sub(-amount);
+            let _ = amount.to_minor_units();
+        }
+    }
+
+    #[test]
+    fn max_precision_uses_all_digits() {
+        for amount in cases(EdgeCaseKind::MaxPrecision) {
+            assert!(amount.raw().digits_count() >= 34, "{amount}");
+        }
+    }
+}
@@ -1,18 +1,16 @@
-use rand::{Rng, rng};
+use crate::utility::generate_data::monetary_value_generator::MonetaryValueGenerator;
 
 /// Generate a vector of monetary values as strings like "12.02"
 /// Values are in the range 1.00 to 1000.00
+///
+/// See [`MonetaryValueGenerator`] for seeded, configurable generation.
 pub fn generate_fake_monetary_values(count: usize) -> Vec<String> {
-    let mut rng = rng();
-    let mut values = Vec::with_capacity(count);
-
-    for _ in 0..count {
-        let amount_cents = rng.random_range(100..=100_000); // 100 cents to 100000 cents
-        let amount = amount_cents as f64 / 100.0;
-        values.push(format!("{:.2}", amount));
-    }
-
-    values
+    MonetaryValueGenerator::<2>::builder()
+        .build()
+        .expect("default generator configuration is valid")
+        .strings()
+        .take(count)
+        .collect()
 }
 
 #[cfg(test)]
@@ -33,7 +31,7 @@ mod tests {
 
             let parsed: f64 = value.parse().unwrap();
             assert!(
-                parsed >= 1.00 && parsed <= 1000.00,
+                (1.00..=1000.00).contains(&parsed),
                 "Value '{}' should be between 1.00 and 1000.00",
                 value
             );
@@ -0,0 +1,420 @@
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::money::Money;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, LogNormal, Pareto};
+use std::io::Write;
+
+/// Upper bound on draws from an unbounded distribution before falling back to clamping into range.
+const MAX_DRAWS: usize = 1_000;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum GeneratorError {
+    #[error(
+        "invalid range {min}..={max}: bounds must be non-negative, in order and fit {decimals} decimals"
+    )]
+    InvalidRange {
+        min: String,
+        max: String,
+        decimals: usize,
+    },
+    #[error("negative ratio must be within 0..=1, got {0}")]
+    InvalidNegativeRatio(f64),
+    #[error("invalid distribution parameters: {0}")]
+    InvalidDistribution(String),
+}
+
+/// Shape of the generated magnitudes within the configured range.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ValueDistribution {
+    /// Every minor unit in the range is equally likely.
+    #[default]
+    Uniform,
+    /// Log-normal in whole currency units, e.g. basket sizes; `mu` and `sigma` of the underlying normal.
+    LogNormal { mu: f64, sigma: f64 },
+    /// Pareto with the range minimum as scale, e.g. a few very large transfers among many small ones.
+    Pareto { shape: f64 },
+}
+
+#[derive(Debug, Clone)]
+pub struct MonetaryValueGeneratorBuilder<const DECIMALS: usize> {
+    seed: Option<u64>,
+    min: Amount<DECIMALS>,
+    max: Amount<DECIMALS>,
+    distribution: ValueDistribution,
+    negative_ratio: f64,
+    currency: Currency,
+}
+
+/// Generates random monetary values with `DECIMALS` decimals.
+///
+/// Values are drawn as integer minor units and only then turned into [`Amount`]s or strings, so no
+/// value ever goes through [`f64`] formatting. With a seed, the same builder always produces the same
+/// values, which makes it suitable for test fixtures.
+///
+/// ```
+/// use fixed_precision_calculations::domain::value_type::amount::Amount;
+/// use fixed_precision_calculations::utility::generate_data::monetary_value_generator::MonetaryValueGenerator;
+///
+/// let values: Vec<String> = MonetaryValueGenerator::<2>::builder()
+///     .seed(42)
+///     .range(Amount::new_scaled_i32(100), Amount::new_scaled_i32(100_000))
+///     .build()
+///     .unwrap()
+///     .strings()
+///     .take(3)
+///     .collect();
+///
+/// assert_eq!(values.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonetaryValueGenerator<const DECIMALS: usize> {
+    rng: StdRng,
+    min: i64,
+    max: i64,
+    distribution: Sampler,
+    negative_ratio: f64,
+    currency: Currency,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Sampler {
+    Uniform,
+    LogNormal(LogNormal<f64>),
+    Pareto(Pareto<f64>),
+}
+
+impl<const DECIMALS: usize> Default for MonetaryValueGeneratorBuilder<DECIMALS> {
+    /// Unseeded, uniform, positive values within 1.00..=1000.00 EUR.
+    fn default() -> Self {
+        Self {
+            seed: None,
+            min: Amount::from(fastnum::D128::ONE),
+            max: Amount::from(fastnum::D128::from_i32(1000)),
+            distribution: ValueDistribution::Uniform,
+            negative_ratio: 0.0,
+            currency: Currency::EUR,
+        }
+    }
+}
+
+impl<const DECIMALS: usize> MonetaryValueGeneratorBuilder<DECIMALS> {
+    /// Makes the generated values reproducible.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Range of the magnitude of the values; the sign is decided by [`Self::negative_ratio`].
+    pub fn range(mut self, min: Amount<DECIMALS>, max: Amount<DECIMALS>) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    pub fn distribution(mut self, distribution: ValueDistribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
+    /// Share of negative values, e.g. 0.1 for roughly one refund in ten.
+    pub fn negative_ratio(mut self, ratio: f64) -> Self {
+        self.negative_ratio = ratio;
+        self
+    }
+
+    /// Currency of the values produced by [`MonetaryValueGenerator::money`].
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    pub fn build(self) -> Result<MonetaryValueGenerator<DECIMALS>, GeneratorError> {
+        let invalid_range = || GeneratorError::InvalidRange {
+            min: self.min.to_string(),
+            max: self.max.to_string(),
+            decimals: DECIMALS,
+        };
+
+        let min = self.min.to_minor_units().ok_or_else(invalid_range)?;
+        let max = self.max.to_minor_units().ok_or_else(invalid_range)?;
+        if min < 0 || min > max {
+            return Err(invalid_range());
+        }
+
+        if !(0.0..=1.0).contains(&self.negative_ratio) {
+            return Err(GeneratorError::InvalidNegativeRatio(self.negative_ratio));
+        }
+
+        let distribution = match self.distribution {
+            ValueDistribution::Uniform => Sampler::Uniform,
+            ValueDistribution::LogNormal { mu, sigma } => LogNormal::new(mu, sigma)
+                .map(Sampler::LogNormal)
+                .map_err(|err| GeneratorError::InvalidDistribution(err.to_string()))?,
+            ValueDistribution::Pareto { shape } => {
+                // Pareto needs a positive scale, so a range starting at zero starts at one minor unit.
+                let scale = min.max(1) as f64;
+                Pareto::new(scale, shape)
+                    .map(Sampler::Pareto)
+                    .map_err(|err| GeneratorError::InvalidDistribution(err.to_string()))?
+            }
+        };
+
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+
+        Ok(MonetaryValueGenerator {
+            rng,
+            min,
+            max,
+            distribution,
+            negative_ratio: self.negative_ratio,
+            currency: self.currency,
+        })
+    }
+}
+
+impl<const DECIMALS: usize> MonetaryValueGenerator<DECIMALS> {
+    pub fn builder() -> MonetaryValueGeneratorBuilder<DECIMALS> {
+        MonetaryValueGeneratorBuilder::default()
+    }
+
+    /// Next value as integer minor units, e.g. 1234 for 12.34.
+    pub fn next_minor_units(&mut self) -> i64 {
+        let magnitude = match self.distribution {
+            Sampler::Uniform => self.rng.random_range(self.min..=self.max),
+            Sampler::LogNormal(distribution) => {
+                // Sampled in whole units, so it does not depend on DECIMALS.
+                let unit = 10_f64.powi(DECIMALS as i32);
+                self.draw_within_range(|rng| distribution.sample(rng) * unit)
+            }
+            Sampler::Pareto(distribution) => self.draw_within_range(|rng| distribution.sample(rng)),
+        };
+
+        if self.negative_ratio > 0.0 && self.rng.random_bool(self.negative_ratio) {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Draws from `sample` until the value lands in range, then truncates it to whole minor units.
+    fn draw_within_range(&mut self, sample: impl Fn(&mut StdRng) -> f64) -> i64 {
+        let (min, max) = (self.min as f64, self.max as f64);
+        let mut value = sample(&mut self.rng);
+
+        for _ in 0..MAX_DRAWS {
+            if (min..=max).contains(&value) {
+                break;
+            }
+            value = sample(&mut self.rng);
+        }
+
+        (value as i64).clamp(self.min, self.max)
+    }
+
+    pub fn next_amount(&mut self) -> Amount<DECIMALS> {
+        Amount::new_scaled_i64(self.next_minor_units())
+    }
+
+    pub fn next_money(&mut self) -> Money<DECIMALS> {
+        Money::new(self.next_amount(), self.currency)
+    }
+
+    /// Next value as a decimal string with exactly `DECIMALS` decimals, e.g. "12.30".
+    pub fn next_string(&mut self) -> String {
+        format_minor_units::<DECIMALS>(self.next_minor_units())
+    }
+
+    /// Endless stream of amounts, e.g. `generator.amounts().take(1_000)`.
+    pub fn amounts(&mut self) -> impl Iterator<Item = Amount<DECIMALS>> + '_ {
+        std::iter::repeat_with(|| self.next_amount())
+    }
+
+    pub fn money(&mut self) -> impl Iterator<Item = Money<DECIMALS>> + '_ {
+        std::iter::repeat_with(|| self.next_money())
+    }
+
+    pub fn strings(&mut self) -> impl Iterator<Item = String> + '_ {
+        std::iter::repeat_with(|| self.next_string())
+    }
+
+    /// Streams `count` values to `writer`, one per line, without holding them in memory.
+    pub fn write_lines(&mut self, writer: &mut impl Write, count: usize) -> std::io::Result<()> {
+        for _ in 0..count {
+            writeln!(writer, "{}", self.next_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats minor units with integer arithmetic only, e.g. -5 → "-0.05" with 2 decimals.
+pub fn format_minor_units<const DECIMALS: usize>(minor_units: i64) -> String {
+    let sign = if minor_units < 0 { "-" } else { "" };
+    let magnitude = minor_units.unsigned_abs();
+
+    if DECIMALS == 0 {
+        return format!("{sign}{magnitude}");
+    }
+
+    let unit = 10_u64.pow(DECIMALS as u32);
+    format!(
+        "{sign}{}.{:0width$}",
+        magnitude / unit,
+        magnitude % unit,
+        width = DECIMALS
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GeneratorError, MonetaryValueGenerator, ValueDistribution, format_minor_units};
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use rstest::rstest;
+
+    fn amount(value: &str) -> Amount<2> {
+        value.parse().unwrap()
+    }
+
+    #[rstest]
+    #[case(1234, "12.34")]
+    #[case(-5, "-0.05")]
+    #[case(0, "0.00")]
+    #[case(i64::MIN, "-92233720368547758.08")]
+    fn format_amount2_minor_units(#[case] minor_units: i64, #[case] expected: &str) {
+        assert_eq!(format_minor_units::<2>(minor_units), expected);
+    }
+
+    #[test]
+    fn format_amount0_minor_units() {
+        assert_eq!(format_minor_units::<0>(-1234), "-1234");
+    }
+
+    #[test]
+    fn seeded_is_reproducible() {
+        let generate = || -> Vec<String> {
+            MonetaryValueGenerator::<2>::builder()
+                .seed(7)
+                .negative_ratio(0.5)
+                .build()
+                .unwrap()
+                .strings()
+                .take(100)
+                .collect()
+        };
+
+        assert_eq!(generate(), generate());
+    }
+
+    #[rstest]
+    #[case(ValueDistribution::Uniform)]
+    #[case(ValueDistribution::LogNormal { mu: 3.0, sigma: 1.0 })]
+    #[case(ValueDistribution::Pareto { shape: 1.16 })]
+    fn stays_within_range(#[case] distribution: ValueDistribution) {
+        let (min, max) = (amount("1.00"), amount("500.00"));
+        let mut generator = MonetaryValueGenerator::<2>::builder()
+            .seed(1)
+            .range(min, max)
+            .distribution(distribution)
+            .build()
+            .unwrap();
+
+        for value in generator.amounts().take(10_000) {
+            assert!((min..=max).contains(&value), "{value}");
+        }
+    }
+
+    #[test]
+    fn strings_have_declared_scale() {
+        let mut generator = MonetaryValueGenerator::<4>::builder()
+            .seed(3)
+            .build()
+            .unwrap();
+
+        for value in generator.strings().take(1_000) {
+            assert_eq!(value.split('.').nth(1).unwrap().len(), 4, "{value}");
+            assert!(value.parse::<Amount<4>>().is_ok(), "{value}");
+        }
+    }
+
+    #[test]
+    fn sign_mix() {
+        let mut generator = MonetaryValueGenerator::<2>::builder()
+            .seed(11)
+            .negative_ratio(0.25)
+            .build()
+            .unwrap();
+
+        let negatives = generator
+            .amounts()
+            .take(10_000)
+            .filter(Amount::is_negative)
+            .count();
+        assert!((2_000..3_000).contains(&negatives), "{negatives}");
+    }
+
+    #[test]
+    fn money_in_currency() {
+        let mut generator = MonetaryValueGenerator::<2>::builder()
+            .seed(5)
+            .currency(Currency::CHF)
+            .build()
+            .unwrap();
+
+        assert!(
+            generator
+                .money()
+                .take(10)
+                .all(|m| m.currency() == Currency::CHF)
+        );
+    }
+
+    #[test]
+    fn write_lines() {
+        let mut generator = MonetaryValueGenerator::<2>::builder()
+            .seed(9)
+            .build()
+            .unwrap();
+        let mut output = Vec::new();
+
+        generator.write_lines(&mut output, 5).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap().lines().count(), 5);
+    }
+
+    #[rstest]
+    #[case(amount("10.00"), amount("1.00"))]
+    #[case(amount("-1.00"), amount("1.00"))]
+    #[case(Amount::new_f64(0.1), amount("1.00"))]
+    fn rejects_invalid_range(#[case] min: Amount<2>, #[case] max: Amount<2>) {
+        let result = MonetaryValueGenerator::<2>::builder()
+            .range(min, max)
+            .build();
+
+        assert!(matches!(result, Err(GeneratorError::InvalidRange { .. })));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let result = MonetaryValueGenerator::<2>::builder()
+            .negative_ratio(1.5)
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            GeneratorError::InvalidNegativeRatio(1.5)
+        );
+
+        let result = MonetaryValueGenerator::<2>::builder()
+            .distribution(ValueDistribution::Pareto { shape: -1.0 })
+            .build();
+        assert!(matches!(
+            result,
+            Err(GeneratorError::InvalidDistribution(_))
+        ));
+    }
+}
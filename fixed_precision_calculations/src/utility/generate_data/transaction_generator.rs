@@ -0,0 +1,566 @@
+use crate::domain::currency_registry::{CURRENCIES, Tender};
+use crate::domain::transaction::{Category, Direction, Transaction};
+use crate::domain::value_type::amount::Amount;
+use crate::domain::value_type::currency::Currency;
+use crate::domain::value_type::uid::Uid;
+use crate::utility::generate_data::monetary_value_generator::{
+    GeneratorError, MonetaryValueGenerator, MonetaryValueGeneratorBuilder, ValueDistribution,
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use fake::Fake;
+use fake::faker::company::en::CompanyName;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Poisson};
+use std::io::Write;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum TransactionGeneratorError {
+    #[error(transparent)]
+    Amounts(#[from] GeneratorError),
+    #[error("transactions per day must be finite and non-negative, got {0}")]
+    InvalidVolume(f64),
+    #[error("seasonal weights must be finite and non-negative")]
+    InvalidSeasonality,
+    #[error("credit ratio must be within 0..=1, got {0}")]
+    InvalidCreditRatio(f64),
+    #[error("at least one currency is required")]
+    NoCurrencies,
+}
+
+/// Multipliers applied to the average daily volume, by month (January first) and by weekday
+/// (Monday first).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Seasonality {
+    pub monthly: [f64; 12],
+    pub weekly: [f64; 7],
+}
+
+/// Output format of [`write_transactions`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ExportFormat {
+    /// Header row followed by one row per transaction.
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+/// First day of seeded generators without an explicit start, so that a seed gives the same
+/// transactions on any day.
+pub const SEEDED_START: NaiveDate = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+#[derive(Debug, Clone)]
+pub struct TransactionGeneratorBuilder {
+    seed: Option<u64>,
+    start: Option<NaiveDate>,
+    days: u32,
+    transactions_per_day: f64,
+    seasonality: Seasonality,
+    credit_ratio: f64,
+    currencies: Vec<Currency>,
+    amounts: MonetaryValueGeneratorBuilder<2>,
+}
+
+/// Generates synthetic account transactions, day by day, for load-testing ETL and reconciliation
+/// code without production data.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use fixed_precision_calculations::utility::generate_data::transaction_generator::{
+///     ExportFormat, Seasonality, TransactionGenerator, write_transactions,
+/// };
+///
+/// let mut generator = TransactionGenerator::builder()
+///     .seed(42)
+///     .start(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+///     .days(31)
+///     .transactions_per_day(50.0)
+///     .seasonality(Seasonality::RETAIL)
+///     .build()
+///     .unwrap();
+///
+/// let mut csv = Vec::new();
+/// write_transactions(&mut csv, ExportFormat::Csv, generator.transactions()).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransactionGenerator {
+    rng: StdRng,
+    day: NaiveDate,
+    remaining_days: u32,
+    transactions_per_day: f64,
+    seasonality: Seasonality,
+    credit_ratio: f64,
+    currencies: Vec<Currency>,
+    amounts: MonetaryValueGenerator<2>,
+}
+
+impl Seasonality {
+    /// Same volume every day.
+    pub const FLAT: Self = Self {
+        monthly: [1.0; 12],
+        weekly: [1.0; 7],
+    };
+
+    /// Card spending: busier weekends, a quiet January and a December peak.
+    pub const RETAIL: Self = Self {
+        monthly: [
+            0.8, 0.85, 0.95, 1.0, 1.0, 1.05, 1.1, 1.05, 0.95, 1.0, 1.15, 1.6,
+        ],
+        weekly: [0.85, 0.9, 0.9, 0.95, 1.15, 1.35, 0.9],
+    };
+
+    fn weight(&self, day: NaiveDate) -> f64 {
+        self.monthly[day.month0() as usize]
+            * self.weekly[day.weekday().num_days_from_monday() as usize]
+    }
+
+    fn is_valid(&self) -> bool {
+        self.monthly
+            .iter()
+            .chain(&self.weekly)
+            .all(|weight| weight.is_finite() && *weight >= 0.0)
+    }
+}
+
+impl Default for Seasonality {
+    fn default() -> Self {
+        Self::FLAT
+    }
+}
+
+impl Default for TransactionGeneratorBuilder {
+    /// Unseeded, 30 days from today (from [`SEEDED_START`] once seeded) at 100 transactions a day,
+    /// 10% credits, in EUR, with log-normal amounts between 0.01 and 10 000.00 (a median around
+    /// 20.00).
+    fn default() -> Self {
+        Self {
+            seed: None,
+            start: None,
+            days: 30,
+            transactions_per_day: 100.0,
+            seasonality: Seasonality::FLAT,
+            credit_ratio: 0.1,
+            currencies: vec![Currency::EUR],
+            amounts: MonetaryValueGenerator::builder()
+                .range(Amount::new_scaled_i32(1), Amount::new_scaled_i32(1_000_000))
+                .distribution(ValueDistribution::LogNormal {
+                    mu: 3.0,
+                    sigma: 1.2,
+                }),
+        }
+    }
+}
+
+impl TransactionGeneratorBuilder {
+    /// Makes the generated transactions, including ids and merchant names, reproducible. Without an
+    /// explicit [`start`](Self::start), seeded generators start on [`SEEDED_START`] rather than today.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn start(mut self, start: NaiveDate) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn days(mut self, days: u32) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Average number of transactions on a day with a seasonal weight of 1.
+    pub fn transactions_per_day(mut self, transactions_per_day: f64) -> Self {
+        self.transactions_per_day = transactions_per_day;
+        self
+    }
+
+    pub fn seasonality(mut self, seasonality: Seasonality) -> Self {
+        self.seasonality = seasonality;
+        self
+    }
+
+    /// Share of credits (salaries, refunds, incoming transfers) among all transactions.
+    pub fn credit_ratio(mut self, ratio: f64) -> Self {
+        self.credit_ratio = ratio;
+        self
+    }
+
+    /// Currencies to pick from, each equally likely.
+    pub fn currencies(mut self, currencies: impl IntoIterator<Item = Currency>) -> Self {
+        self.currencies = currencies.into_iter().collect();
+        self
+    }
+
+    /// Range and distribution of the amounts; their sign is ignored in favour of the direction.
+    pub fn amounts(mut self, amounts: MonetaryValueGeneratorBuilder<2>) -> Self {
+        self.amounts = amounts;
+        self
+    }
+
+    pub fn build(self) -> Result<TransactionGenerator, TransactionGeneratorError> {
+        if !self.transactions_per_day.is_finite() || self.transactions_per_day < 0.0 {
+            return Err(TransactionGeneratorError::InvalidVolume(
+                self.transactions_per_day,
+            ));
+        }
+
+        if !self.seasonality.is_valid() {
+            return Err(TransactionGeneratorError::InvalidSeasonality);
+        }
+
+        if !(0.0..=1.0).contains(&self.credit_ratio) {
+            return Err(TransactionGeneratorError::InvalidCreditRatio(
+                self.credit_ratio,
+            ));
+        }
+
+        if self.currencies.is_empty() {
+            return Err(TransactionGeneratorError::NoCurrencies);
+        }
+
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+
+        let amounts = match self.seed {
+            Some(_) => self.amounts.seed(rng.random()),
+            None => self.amounts,
+        };
+
+        Ok(TransactionGenerator {
+            rng,
+            day: self.start.unwrap_or_else(|| match self.seed {
+                Some(_) => SEEDED_START,
+                None => Utc::now().date_naive(),
+            }),
+            remaining_days: self.days,
+            transactions_per_day: self.transactions_per_day,
+            seasonality: self.seasonality,
+            credit_ratio: self.credit_ratio,
+            currencies: self.currencies,
+            amounts: amounts.negative_ratio(0.0).build()?,
+        })
+    }
+}
+
+impl TransactionGenerator {
+    pub fn builder() -> TransactionGeneratorBuilder {
+        TransactionGeneratorBuilder::default()
+    }
+
+    /// Transactions of the next day, ordered by booking time, or `None` once all days are generated.
+    pub fn next_day(&mut self) -> Option<Vec<Transaction>> {
+        if self.remaining_days == 0 {
+            return None;
+        }
+
+        let day = self.day;
+        self.day = day.succ_opt()?;
+        self.remaining_days -= 1;
+
+        let mean = self.transactions_per_day * self.seasonality.weight(day);
+        let count = match Poisson::new(mean) {
+            Ok(poisson) => poisson.sample(&mut self.rng) as usize,
+            // Only a zero mean is rejected, as all weights are validated.
+            Err(_) => 0,
+        };
+
+        let midnight = day.and_hms_opt(0, 0, 0)?.and_utc();
+        let mut offsets: Vec<i64> = (0..count)
+            .map(|_| self.rng.random_range(0..SECONDS_PER_DAY))
+            .collect();
+        offsets.sort_unstable();
+
+        Some(
+            offsets
+                .into_iter()
+                .map(|offset| self.transaction(midnight + Duration::seconds(offset)))
+                .collect(),
+        )
+    }
+
+    /// All remaining transactions, generated one day at a time.
+    pub fn transactions(&mut self) -> impl Iterator<Item = Transaction> + '_ {
+        std::iter::from_fn(|| self.next_day()).flatten()
+    }
+
+    fn transaction(&mut self, booked_at: DateTime<Utc>) -> Transaction {
+        let (direction, categories) = if self.rng.random_bool(self.credit_ratio) {
+            (Direction::Credit, Category::CREDITS.as_slice())
+        } else {
+            (Direction::Debit, Category::DEBITS.as_slice())
+        };
+
+        let currency = *self
+            .currencies
+            .choose(&mut self.rng)
+            .expect("currencies are checked to be non-empty");
+
+        let amount = self.amounts.next_amount();
+        let amount = match CURRENCIES.get(currency) {
+            // Whole yen for JPY and so on.
+            Ok(spec) => spec.settle(amount, Tender::Card).payable,
+            Err(_) => amount,
+        };
+
+        Transaction {
+            id: Uid::from_rng(&mut self.rng),
+            booked_at,
+            merchant: CompanyName().fake_with_rng(&mut self.rng),
+            category: *categories
+                .choose(&mut self.rng)
+                .expect("categories are not empty"),
+            currency,
+            amount,
+            direction,
+        }
+    }
+}
+
+/// Streams `transactions` to `writer` in the given format. CSV is written with the `csv` crate, so
+/// it reads back with [`CsvAmountReaderBuilder`](crate::ingest::csv_reader::CsvAmountReaderBuilder).
+pub fn write_transactions(
+    writer: &mut impl Write,
+    format: ExportFormat,
+    transactions: impl IntoIterator<Item = Transaction>,
+) -> std::io::Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            let mut csv = csv::Writer::from_writer(writer);
+            csv.write_record([
+                "id",
+                "booked_at",
+                "merchant",
+                "category",
+                "currency",
+                "amount",
+                "direction",
+            ])?;
+            for transaction in transactions {
+                csv.write_record([
+                    transaction.id.to_string(),
+                    transaction
+                        .booked_at
+                        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                    transaction.merchant,
+                    transaction.category.to_string(),
+                    transaction.currency.to_string(),
+                    transaction.amount.to_string(),
+                    transaction.direction.to_string(),
+                ])?;
+            }
+            csv.flush()?;
+        }
+        ExportFormat::JsonLines => {
+            for transaction in transactions {
+                serde_json::to_writer(&mut *writer, &transaction)?;
+                writeln!(writer)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ExportFormat, SEEDED_START, Seasonality, TransactionGenerator, TransactionGeneratorError,
+        write_transactions,
+    };
+    use crate::domain::transaction::{Direction, Transaction};
+    use crate::domain::value_type::amount::Amount;
+    use crate::domain::value_type::currency::Currency;
+    use crate::ingest::csv_reader::CsvAmountReaderBuilder;
+    use crate::utility::generate_data::monetary_value_generator::MonetaryValueGenerator;
+    use chrono::{Datelike, NaiveDate};
+    use rstest::rstest;
+
+    fn generator(seed: u64) -> TransactionGenerator {
+        TransactionGenerator::builder()
+            .seed(seed)
+            .start(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())
+            .days(62)
+            .transactions_per_day(20.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn seeded_is_reproducible() {
+        let first: Vec<Transaction> = generator(3).transactions().collect();
+        let second: Vec<Transaction> = generator(3).transactions().collect();
+
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn seeded_without_start_is_reproducible() {
+        let generator = || {
+            TransactionGenerator::builder()
+                .seed(3)
+                .days(2)
+                .build()
+                .unwrap()
+        };
+        let first: Vec<Transaction> = generator().transactions().collect();
+        let second: Vec<Transaction> = generator().transactions().collect();
+
+        assert_eq!(first[0].booked_at.date_naive(), SEEDED_START);
+        assert_eq!(
+            first.iter().map(|t| t.booked_at).collect::<Vec<_>>(),
+            second.iter().map(|t| t.booked_at).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn ordered_by_booking_time_within_period() {
+        let transactions: Vec<Transaction> = generator(5).transactions().collect();
+
+        assert!(transactions.is_sorted_by_key(|t| t.booked_at));
+        assert_eq!(
+            transactions.first().unwrap().booked_at.date_naive(),
+            NaiveDate::from_ymd_opt(2024, 12, 1).unwrap()
+        );
+        assert!(
+            transactions.last().unwrap().booked_at.date_naive()
+                < NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn seasonality_shapes_volume() {
+        let mut generator = TransactionGenerator::builder()
+            .seed(7)
+            .start(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())
+            .days(62)
+            .seasonality(Seasonality::RETAIL)
+            .build()
+            .unwrap();
+
+        let (december, january) =
+            generator
+                .transactions()
+                .fold((0, 0), |(december, january), t| match t.booked_at.month() {
+                    12 => (december + 1, january),
+                    _ => (december, january + 1),
+                });
+
+        assert!(december > january * 3 / 2, "{december} vs {january}");
+    }
+
+    #[test]
+    fn amounts_currencies_and_directions() {
+        let (min, max) = (Amount::new_scaled_i32(500), Amount::new_scaled_i32(5_000));
+        let mut generator = TransactionGenerator::builder()
+            .seed(11)
+            .days(10)
+            .credit_ratio(0.5)
+            .currencies([Currency::EUR, Currency::JPY])
+            .amounts(
+                MonetaryValueGenerator::builder()
+                    .range(min, max)
+                    .negative_ratio(0.5),
+            )
+            .build()
+            .unwrap();
+
+        let transactions: Vec<Transaction> = generator.transactions().collect();
+
+        assert!(
+            transactions
+                .iter()
+                .any(|t| t.direction == Direction::Credit)
+        );
+        assert!(transactions.iter().any(|t| t.direction == Direction::Debit));
+        for t in &transactions {
+            assert!((min..=max).contains(&t.amount), "{}", t.amount);
+            if t.currency == Currency::JPY {
+                assert_eq!(t.amount.raw(), t.amount.raw().floor());
+            }
+        }
+    }
+
+    #[test]
+    fn export_csv() {
+        let transactions: Vec<Transaction> = generator(13).transactions().take(50).collect();
+        let mut output = Vec::new();
+
+        write_transactions(&mut output, ExportFormat::Csv, transactions.clone()).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(
+            lines.next(),
+            Some("id,booked_at,merchant,category,currency,amount,direction")
+        );
+        for (line, transaction) in lines.zip(&transactions) {
+            assert!(line.starts_with(&transaction.id.to_string()), "{line}");
+            assert!(
+                line.ends_with(&format!(
+                    ",{},{}",
+                    transaction.amount, transaction.direction
+                )),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn export_csv_reads_back() {
+        let mut transactions: Vec<Transaction> = generator(19).transactions().take(50).collect();
+        transactions[0].merchant = "Smith, \"Jones\"\nand Co".to_string();
+        let mut output = Vec::new();
+
+        write_transactions(&mut output, ExportFormat::Csv, transactions.clone()).unwrap();
+
+        let records = CsvAmountReaderBuilder::new()
+            .column("amount")
+            .currency_column("currency")
+            .from_reader::<_, 2>(output.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(records.len(), transactions.len());
+        for (record, transaction) in records.iter().zip(&transactions) {
+            assert_eq!(record.amounts, [transaction.amount]);
+            assert_eq!(record.currency, Some(transaction.currency));
+        }
+    }
+
+    #[test]
+    fn export_json_lines() {
+        let transactions: Vec<Transaction> = generator(17).transactions().take(50).collect();
+        let mut output = Vec::new();
+
+        write_transactions(&mut output, ExportFormat::JsonLines, transactions.clone()).unwrap();
+
+        let parsed: Vec<Transaction> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, transactions);
+    }
+
+    #[rstest]
+    #[case(TransactionGenerator::builder().transactions_per_day(-1.0), TransactionGeneratorError::InvalidVolume(-1.0))]
+    #[case(TransactionGenerator::builder().credit_ratio(2.0), TransactionGeneratorError::InvalidCreditRatio(2.0))]
+    #[case(TransactionGenerator::builder().currencies([]), TransactionGeneratorError::NoCurrencies)]
+    #[case(
+        TransactionGenerator::builder().seasonality(Seasonality { monthly: [f64::NAN; 12], weekly: [1.0; 7] }),
+        TransactionGeneratorError::InvalidSeasonality
+    )]
+    fn rejects_invalid_configuration(
+        #[case] builder: super::TransactionGeneratorBuilder,
+        #[case] expected: TransactionGeneratorError,
+    ) {
+        assert_eq!(builder.build().unwrap_err(), expected);
+    }
+}